@@ -47,11 +47,11 @@ export class Game {
                         console.log("Subscription applied");
                     })
                     .subscribe([
-                        `SELECT * FROM unit WHERE game_id = ${this.game_id}`,
-                        `SELECT * FROM terrain WHERE game_id = ${this.game_id}`,
+                        `SELECT * FROM visible_units WHERE game_id = ${this.game_id}`,
+                        `SELECT * FROM visible_terrains WHERE game_id = ${this.game_id}`,
                         `SELECT * FROM action WHERE game_id = ${this.game_id}`,
-                        `SELECT * FROM underlay WHERE game_id = ${this.game_id}`,
-                        `SELECT * FROM overlay WHERE game_id = ${this.game_id}`
+                        `SELECT * FROM visible_underlays WHERE game_id = ${this.game_id}`,
+                        `SELECT * FROM visible_overlays WHERE game_id = ${this.game_id}`
                     ]);
             })
             .build();
@@ -123,7 +123,6 @@ export class Game {
     private setupEntityCallbacks<T extends { id: bigint }>(
         collection: { 
             onInsert: (cb: (ctx: EventContext, row: T) => void) => void;
-            onUpdate: (cb: (ctx: EventContext, row: T) => void) => void;
             onDelete: (cb: (ctx: EventContext, row: T) => void) => void;
         },
         entities: T[],
@@ -147,33 +146,34 @@ export class Game {
             }
         };
 
+        // Views have no primary key, so an edited row arrives as a delete
+        // followed by an insert
         collection.onInsert(upsertCallback);
-        collection.onUpdate(upsertCallback);
         collection.onDelete(deleteCallback);
     }
 
     private setupDatabaseCallbacks() {
         // Handle all entity types
         this.setupEntityCallbacks<Unit>(
-            this.dbConnection.db.unit,
+            this.dbConnection.db.visibleUnits,
             this.currentGameState.units,
             'units'
         );
         
         this.setupEntityCallbacks<Terrain>(
-            this.dbConnection.db.terrain,
+            this.dbConnection.db.visibleTerrains,
             this.currentGameState.terrains,
             'terrain'
         );
         
         this.setupEntityCallbacks<Underlay>(
-            this.dbConnection.db.underlay,
+            this.dbConnection.db.visibleUnderlays,
             this.currentGameState.underlays,
             'underlay'
         );
         
         this.setupEntityCallbacks<Overlay>(
-            this.dbConnection.db.overlay,
+            this.dbConnection.db.visibleOverlays,
             this.currentGameState.overlays,
             'overlay'
         );
@@ -33,6 +33,7 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+
 import { Action } from "./action_type";
 import { GameState as __GameState } from "./game_state_type";
 
@@ -23,7 +23,6 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
-import { GameState as __GameState } from "./game_state_type";
 
 import type {
   CallReducerFlags,
@@ -34,6 +33,8 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { GameState as __GameState } from "./game_state_type";
+
 export type Action = {
   id: bigint,
   gameId: bigint,
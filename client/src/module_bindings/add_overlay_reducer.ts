@@ -23,6 +23,7 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+
 import type {
   CallReducerFlags,
   DbContext,
@@ -32,9 +33,10 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-
 import { ShapeType as __ShapeType } from "./shape_type_type";
 import { Position as __Position } from "./position_type";
+import { TextContent as __TextContent } from "./text_content_type";
+import { Visibility as __Visibility } from "./visibility_type";
 
 export type AddOverlay = {
   gameId: bigint,
@@ -42,6 +44,8 @@ export type AddOverlay = {
   size: number[],
   color: string,
   position: __Position[],
+  text: __TextContent | undefined,
+  visibility: __Visibility | undefined,
 };
 
 /**
@@ -59,6 +63,8 @@ export namespace AddOverlay {
       new ProductTypeElement("size", AlgebraicType.createArrayType(AlgebraicType.createU32Type())),
       new ProductTypeElement("color", AlgebraicType.createStringType()),
       new ProductTypeElement("position", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("text", AlgebraicType.createOptionType(__TextContent.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("visibility", AlgebraicType.createOptionType(__Visibility.getTypeScriptAlgebraicType())),
     ]);
   }
 
@@ -33,9 +33,9 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-
-import { Position as __Position } from "./position_type";
 import { ShapeType as __ShapeType } from "./shape_type_type";
+import { Position as __Position } from "./position_type";
+import { Visibility as __Visibility } from "./visibility_type";
 
 export type AddTerrain = {
   gameId: bigint,
@@ -44,6 +44,7 @@ export type AddTerrain = {
   color: string,
   position: __Position[],
   traversable: boolean,
+  visibility: __Visibility | undefined,
 };
 
 /**
@@ -62,6 +63,7 @@ export namespace AddTerrain {
       new ProductTypeElement("color", AlgebraicType.createStringType()),
       new ProductTypeElement("position", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
       new ProductTypeElement("traversable", AlgebraicType.createBoolType()),
+      new ProductTypeElement("visibility", AlgebraicType.createOptionType(__Visibility.getTypeScriptAlgebraicType())),
     ]);
   }
 
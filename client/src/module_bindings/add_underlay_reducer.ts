@@ -23,6 +23,7 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+
 import type {
   CallReducerFlags,
   DbContext,
@@ -32,9 +33,9 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-
 import { ShapeType as __ShapeType } from "./shape_type_type";
 import { Position as __Position } from "./position_type";
+import { Visibility as __Visibility } from "./visibility_type";
 
 export type AddUnderlay = {
   gameId: bigint,
@@ -42,6 +43,7 @@ export type AddUnderlay = {
   size: number[],
   color: string,
   position: __Position[],
+  visibility: __Visibility | undefined,
 };
 
 /**
@@ -59,6 +61,7 @@ export namespace AddUnderlay {
       new ProductTypeElement("size", AlgebraicType.createArrayType(AlgebraicType.createU32Type())),
       new ProductTypeElement("color", AlgebraicType.createStringType()),
       new ProductTypeElement("position", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("visibility", AlgebraicType.createOptionType(__Visibility.getTypeScriptAlgebraicType())),
     ]);
   }
 
@@ -33,9 +33,10 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-
-import { Position as __Position } from "./position_type";
 import { ShapeType as __ShapeType } from "./shape_type_type";
+import { Position as __Position } from "./position_type";
+import { TextContent as __TextContent } from "./text_content_type";
+import { Visibility as __Visibility } from "./visibility_type";
 
 export type AddUnit = {
   gameId: bigint,
@@ -43,6 +44,8 @@ export type AddUnit = {
   size: number[],
   color: string,
   position: __Position[],
+  text: __TextContent | undefined,
+  visibility: __Visibility | undefined,
 };
 
 /**
@@ -60,6 +63,8 @@ export namespace AddUnit {
       new ProductTypeElement("size", AlgebraicType.createArrayType(AlgebraicType.createU32Type())),
       new ProductTypeElement("color", AlgebraicType.createStringType()),
       new ProductTypeElement("position", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("text", AlgebraicType.createOptionType(__TextContent.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("visibility", AlgebraicType.createOptionType(__Visibility.getTypeScriptAlgebraicType())),
     ]);
   }
 
@@ -29,32 +29,31 @@ import type {
   DbContext,
   ErrorContextInterface,
   Event,
-  EventContext,
   EventContextInterface,
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
 
-import { Unit } from "./unit_type";
-import { ShapeType as __ShapeType } from "./shape_type_type";
+import { AreaTemplate } from "./area_template_type";
+import { TemplateKind as __TemplateKind } from "./template_kind_type";
 import { Position as __Position } from "./position_type";
 
 import type { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `unit`.
+ * Table handle for the table `area_template`.
  *
- * Obtain a handle from the [`unit`] property on [`RemoteTables`],
- * like `ctx.db.unit`.
+ * Obtain a handle from the [`areaTemplate`] property on [`RemoteTables`],
+ * like `ctx.db.areaTemplate`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.unit.on_insert(...)`.
+ * like `ctx.db.areaTemplate.on_insert(...)`.
  */
-export class UnitTableHandle {
-  tableCache: TableCache<Unit>;
+export class AreaTemplateTableHandle {
+  tableCache: TableCache<AreaTemplate>;
 
-  constructor(tableCache: TableCache<Unit>) {
+  constructor(tableCache: TableCache<AreaTemplate>) {
     this.tableCache = tableCache;
   }
 
@@ -62,24 +61,24 @@ export class UnitTableHandle {
     return this.tableCache.count();
   }
 
-  iter(): Iterable<Unit> {
+  iter(): Iterable<AreaTemplate> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `unit`,
+   * Access to the `id` unique index on the table `area_template`,
    * which allows point queries on the field of the same name
-   * via the [`UnitIdUnique.find`] method.
+   * via the [`AreaTemplateIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.unit.id().find(...)`.
+   * like `ctx.db.areaTemplate.id().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `unit`.
+   * Get a handle on the `id` unique index on the table `area_template`.
    */
   id = {
     // Find the subscribed row whose `id` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: bigint): Unit | undefined => {
+    find: (col_val: bigint): AreaTemplate | undefined => {
       for (let row of this.tableCache.iter()) {
         if (deepEqual(row.id, col_val)) {
           return row;
@@ -88,27 +87,27 @@ export class UnitTableHandle {
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: Unit) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: AreaTemplate) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: Unit) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: AreaTemplate) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: Unit) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: AreaTemplate) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: Unit) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: AreaTemplate) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: Unit, newRow: Unit) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: AreaTemplate, newRow: AreaTemplate) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: Unit, newRow: Unit) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: AreaTemplate, newRow: AreaTemplate) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
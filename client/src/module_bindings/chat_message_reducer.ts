@@ -33,6 +33,7 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+
 export type ChatMessage = {
   gameId: bigint,
   message: string,
@@ -33,11 +33,13 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { Layer as __Layer } from "./layer_type";
 
 export type DeleteAtCoordinates = {
   gameId: bigint,
   x: number,
   y: number,
+  layers: __Layer[],
 };
 
 /**
@@ -53,6 +55,7 @@ export namespace DeleteAtCoordinates {
       new ProductTypeElement("gameId", AlgebraicType.createU64Type()),
       new ProductTypeElement("x", AlgebraicType.createU32Type()),
       new ProductTypeElement("y", AlgebraicType.createU32Type()),
+      new ProductTypeElement("layers", AlgebraicType.createArrayType(__Layer.getTypeScriptAlgebraicType())),
     ]);
   }
 
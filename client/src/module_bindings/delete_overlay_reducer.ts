@@ -23,6 +23,7 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
+
 import type {
   CallReducerFlags,
   DbContext,
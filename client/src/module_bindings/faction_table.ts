@@ -33,23 +33,24 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-import { SelectedUnit } from "./selected_unit_type";
-import type { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
+
+import { Faction } from "./faction_type";
+import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `selected_unit`.
+ * Table handle for the table `faction`.
  *
- * Obtain a handle from the [`selectedUnit`] property on [`RemoteTables`],
- * like `ctx.db.selectedUnit`.
+ * Obtain a handle from the [`faction`] property on [`RemoteTables`],
+ * like `ctx.db.faction`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.selectedUnit.on_insert(...)`.
+ * like `ctx.db.faction.on_insert(...)`.
  */
-export class SelectedUnitTableHandle {
-  tableCache: TableCache<SelectedUnit>;
+export class FactionTableHandle {
+  tableCache: TableCache<Faction>;
 
-  constructor(tableCache: TableCache<SelectedUnit>) {
+  constructor(tableCache: TableCache<Faction>) {
     this.tableCache = tableCache;
   }
 
@@ -57,24 +58,24 @@ export class SelectedUnitTableHandle {
     return this.tableCache.count();
   }
 
-  iter(): Iterable<SelectedUnit> {
+  iter(): Iterable<Faction> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `selected_unit`,
+   * Access to the `id` unique index on the table `faction`,
    * which allows point queries on the field of the same name
-   * via the [`SelectedUnitIdUnique.find`] method.
+   * via the [`FactionIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.selectedUnit.id().find(...)`.
+   * like `ctx.db.faction.id().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `selected_unit`.
+   * Get a handle on the `id` unique index on the table `faction`.
    */
   id = {
     // Find the subscribed row whose `id` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: bigint): SelectedUnit | undefined => {
+    find: (col_val: bigint): Faction | undefined => {
       for (let row of this.tableCache.iter()) {
         if (deepEqual(row.id, col_val)) {
           return row;
@@ -83,27 +84,27 @@ export class SelectedUnitTableHandle {
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: SelectedUnit) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: Faction) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: SelectedUnit) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: Faction) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: SelectedUnit) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: Faction) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: SelectedUnit) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: Faction) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: SelectedUnit, newRow: SelectedUnit) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: Faction, newRow: Faction) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: SelectedUnit, newRow: SelectedUnit) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: Faction, newRow: Faction) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
@@ -23,10 +23,7 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
-import { Terrain as __Terrain } from "./terrain_type";
-import { Unit as __Unit } from "./unit_type";
-import { Underlay as __Underlay } from "./underlay_type";
-import { Overlay as __Overlay } from "./overlay_type";
+
 import type {
   CallReducerFlags,
   DbContext,
@@ -36,6 +33,10 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { Terrain as __Terrain } from "./terrain_type";
+import { Unit as __Unit } from "./unit_type";
+import { Underlay as __Underlay } from "./underlay_type";
+import { Overlay as __Overlay } from "./overlay_type";
 
 export type GameState = {
   terrains: __Terrain[],
@@ -33,11 +33,16 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { CoherencyMode as __CoherencyMode } from "./coherency_mode_type";
 
 export type Game = {
   id: bigint,
   name: string,
   description: string,
+  round: number,
+  coherencyMode: __CoherencyMode,
+  fogOfWar: boolean,
+  gm: Identity | undefined,
 };
 
 /**
@@ -53,6 +58,10 @@ export namespace Game {
       new ProductTypeElement("id", AlgebraicType.createU64Type()),
       new ProductTypeElement("name", AlgebraicType.createStringType()),
       new ProductTypeElement("description", AlgebraicType.createStringType()),
+      new ProductTypeElement("round", AlgebraicType.createU32Type()),
+      new ProductTypeElement("coherencyMode", __CoherencyMode.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("fogOfWar", AlgebraicType.createBoolType()),
+      new ProductTypeElement("gm", AlgebraicType.createOptionType(AlgebraicType.createIdentityType())),
     ]);
   }
 
@@ -35,7 +35,9 @@ import type {
 } from "@clockworklabs/spacetimedb-sdk";
 
 import { Game } from "./game_type";
-import { type EventContext, type Reducer, RemoteReducers, RemoteTables } from ".";
+import { CoherencyMode as __CoherencyMode } from "./coherency_mode_type";
+
+import type { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
  * Table handle for the table `games`.
@@ -35,6 +35,8 @@ import type {
 } from "@clockworklabs/spacetimedb-sdk";
 
 // Import and reexport all reducer arg types
+import { AddAreaTemplate } from "./add_area_template_reducer.ts";
+export { AddAreaTemplate };
 import { AddOverlay } from "./add_overlay_reducer.ts";
 export { AddOverlay };
 import { AddTerrain } from "./add_terrain_reducer.ts";
@@ -43,66 +45,254 @@ import { AddUnderlay } from "./add_underlay_reducer.ts";
 export { AddUnderlay };
 import { AddUnit } from "./add_unit_reducer.ts";
 export { AddUnit };
+import { AdvanceRound } from "./advance_round_reducer.ts";
+export { AdvanceRound };
+import { ApplyAreaTemplate } from "./apply_area_template_reducer.ts";
+export { ApplyAreaTemplate };
+import { ApplyCondition } from "./apply_condition_reducer.ts";
+export { ApplyCondition };
+import { AssignPlayer } from "./assign_player_reducer.ts";
+export { AssignPlayer };
+import { AwardPoints } from "./award_points_reducer.ts";
+export { AwardPoints };
 import { ChatMessage } from "./chat_message_reducer.ts";
 export { ChatMessage };
+import { CheckFacingArc } from "./check_facing_arc_reducer.ts";
+export { CheckFacingArc };
+import { CheckLineOfSight } from "./check_line_of_sight_reducer.ts";
+export { CheckLineOfSight };
+import { CommitPath } from "./commit_path_reducer.ts";
+export { CommitPath };
+import { CreateFaction } from "./create_faction_reducer.ts";
+export { CreateFaction };
+import { CreateSquad } from "./create_squad_reducer.ts";
+export { CreateSquad };
+import { CreateUnitTemplate } from "./create_unit_template_reducer.ts";
+export { CreateUnitTemplate };
+import { DecrementUnitAttribute } from "./decrement_unit_attribute_reducer.ts";
+export { DecrementUnitAttribute };
 import { DeleteAll } from "./delete_all_reducer.ts";
 export { DeleteAll };
+import { DeleteAreaTemplate } from "./delete_area_template_reducer.ts";
+export { DeleteAreaTemplate };
 import { DeleteAtCoordinates } from "./delete_at_coordinates_reducer.ts";
 export { DeleteAtCoordinates };
+import { DeleteFaction } from "./delete_faction_reducer.ts";
+export { DeleteFaction };
 import { DeleteOverlay } from "./delete_overlay_reducer.ts";
 export { DeleteOverlay };
+import { DeleteSquad } from "./delete_squad_reducer.ts";
+export { DeleteSquad };
 import { DeleteTerrain } from "./delete_terrain_reducer.ts";
 export { DeleteTerrain };
 import { DeleteUnderlay } from "./delete_underlay_reducer.ts";
 export { DeleteUnderlay };
 import { DeleteUnit } from "./delete_unit_reducer.ts";
 export { DeleteUnit };
+import { DeleteUnitAttribute } from "./delete_unit_attribute_reducer.ts";
+export { DeleteUnitAttribute };
+import { DeleteUnitTemplate } from "./delete_unit_template_reducer.ts";
+export { DeleteUnitTemplate };
+import { Disembark } from "./disembark_reducer.ts";
+export { Disembark };
+import { DisembarkAll } from "./disembark_all_reducer.ts";
+export { DisembarkAll };
+import { Embark } from "./embark_reducer.ts";
+export { Embark };
 import { HandleMouseEvent } from "./handle_mouse_event_reducer.ts";
 export { HandleMouseEvent };
 import { IdentityConnected } from "./identity_connected_reducer.ts";
 export { IdentityConnected };
 import { IdentityDisconnected } from "./identity_disconnected_reducer.ts";
 export { IdentityDisconnected };
+import { ImportRoster } from "./import_roster_reducer.ts";
+export { ImportRoster };
+import { IncrementUnitAttribute } from "./increment_unit_attribute_reducer.ts";
+export { IncrementUnitAttribute };
+import { Measure } from "./measure_reducer.ts";
+export { Measure };
+import { MigrateRectangleGeometry } from "./migrate_rectangle_geometry_reducer.ts";
+export { MigrateRectangleGeometry };
+import { RemoveCondition } from "./remove_condition_reducer.ts";
+export { RemoveCondition };
+import { RequestPath } from "./request_path_reducer.ts";
+export { RequestPath };
+import { Reveal } from "./reveal_reducer.ts";
+export { Reveal };
 import { RollDice } from "./roll_dice_reducer.ts";
 export { RollDice };
+import { RotateUnit } from "./rotate_unit_reducer.ts";
+export { RotateUnit };
+import { SendTeamMessage } from "./send_team_message_reducer.ts";
+export { SendTeamMessage };
+import { SetCoherencyMode } from "./set_coherency_mode_reducer.ts";
+export { SetCoherencyMode };
+import { SetFactionColor } from "./set_faction_color_reducer.ts";
+export { SetFactionColor };
+import { SetFogOfWar } from "./set_fog_of_war_reducer.ts";
+export { SetFogOfWar };
+import { SetGameMaster } from "./set_game_master_reducer.ts";
+export { SetGameMaster };
+import { SetOverlayText } from "./set_overlay_text_reducer.ts";
+export { SetOverlayText };
+import { SetSquadRule } from "./set_squad_rule_reducer.ts";
+export { SetSquadRule };
+import { SetTerrainKind } from "./set_terrain_kind_reducer.ts";
+export { SetTerrainKind };
+import { SetTransportCapacity } from "./set_transport_capacity_reducer.ts";
+export { SetTransportCapacity };
+import { SetUnitArcs } from "./set_unit_arcs_reducer.ts";
+export { SetUnitArcs };
+import { SetUnitAttribute } from "./set_unit_attribute_reducer.ts";
+export { SetUnitAttribute };
+import { SetUnitFaction } from "./set_unit_faction_reducer.ts";
+export { SetUnitFaction };
+import { SetUnitSquad } from "./set_unit_squad_reducer.ts";
+export { SetUnitSquad };
+import { SetUnitText } from "./set_unit_text_reducer.ts";
+export { SetUnitText };
+import { SetVisibility } from "./set_visibility_reducer.ts";
+export { SetVisibility };
+import { SetVisionRadius } from "./set_vision_radius_reducer.ts";
+export { SetVisionRadius };
+import { SpawnFromTemplate } from "./spawn_from_template_reducer.ts";
+export { SpawnFromTemplate };
+import { ToggleTerrain } from "./toggle_terrain_reducer.ts";
+export { ToggleTerrain };
+import { UnassignPlayer } from "./unassign_player_reducer.ts";
+export { UnassignPlayer };
+import { UpdateOverlay } from "./update_overlay_reducer.ts";
+export { UpdateOverlay };
+import { UpdateTerrain } from "./update_terrain_reducer.ts";
+export { UpdateTerrain };
+import { UpdateUnderlay } from "./update_underlay_reducer.ts";
+export { UpdateUnderlay };
+import { UpdateUnit } from "./update_unit_reducer.ts";
+export { UpdateUnit };
 
 // Import and reexport all table handle types
 import { ActionTableHandle } from "./action_table.ts";
 export { ActionTableHandle };
+import { AreaTemplateTableHandle } from "./area_template_table.ts";
+export { AreaTemplateTableHandle };
+import { FactionTableHandle } from "./faction_table.ts";
+export { FactionTableHandle };
+import { FactionMemberTableHandle } from "./faction_member_table.ts";
+export { FactionMemberTableHandle };
 import { GamesTableHandle } from "./games_table.ts";
 export { GamesTableHandle };
-import { OverlayTableHandle } from "./overlay_table.ts";
-export { OverlayTableHandle };
-import { SelectedUnitTableHandle } from "./selected_unit_table.ts";
-export { SelectedUnitTableHandle };
-import { TerrainTableHandle } from "./terrain_table.ts";
-export { TerrainTableHandle };
-import { UnderlayTableHandle } from "./underlay_table.ts";
-export { UnderlayTableHandle };
-import { UnitTableHandle } from "./unit_table.ts";
-export { UnitTableHandle };
+import { MyLosResultsTableHandle } from "./my_los_results_table.ts";
+export { MyLosResultsTableHandle };
+import { MyMeasurementsTableHandle } from "./my_measurements_table.ts";
+export { MyMeasurementsTableHandle };
+import { MyTeamMessagesTableHandle } from "./my_team_messages_table.ts";
+export { MyTeamMessagesTableHandle };
+import { UnitTemplateTableHandle } from "./unit_template_table.ts";
+export { UnitTemplateTableHandle };
+import { VisibleConditionsTableHandle } from "./visible_conditions_table.ts";
+export { VisibleConditionsTableHandle };
+import { VisibleEmbarkedUnitsTableHandle } from "./visible_embarked_units_table.ts";
+export { VisibleEmbarkedUnitsTableHandle };
+import { VisibleOverlaysTableHandle } from "./visible_overlays_table.ts";
+export { VisibleOverlaysTableHandle };
+import { VisiblePlannedPathsTableHandle } from "./visible_planned_paths_table.ts";
+export { VisiblePlannedPathsTableHandle };
+import { VisibleSelectedUnitsTableHandle } from "./visible_selected_units_table.ts";
+export { VisibleSelectedUnitsTableHandle };
+import { VisibleSquadsTableHandle } from "./visible_squads_table.ts";
+export { VisibleSquadsTableHandle };
+import { VisibleTerrainsTableHandle } from "./visible_terrains_table.ts";
+export { VisibleTerrainsTableHandle };
+import { VisibleTransportsTableHandle } from "./visible_transports_table.ts";
+export { VisibleTransportsTableHandle };
+import { VisibleUnderlaysTableHandle } from "./visible_underlays_table.ts";
+export { VisibleUnderlaysTableHandle };
+import { VisibleUnitArcsTableHandle } from "./visible_unit_arcs_table.ts";
+export { VisibleUnitArcsTableHandle };
+import { VisibleUnitAttributesTableHandle } from "./visible_unit_attributes_table.ts";
+export { VisibleUnitAttributesTableHandle };
+import { VisibleUnitsTableHandle } from "./visible_units_table.ts";
+export { VisibleUnitsTableHandle };
 
 // Import and reexport all types
 import { Action } from "./action_type.ts";
 export { Action };
+import { AreaTemplate } from "./area_template_type.ts";
+export { AreaTemplate };
+import { AttributeValue } from "./attribute_value_type.ts";
+export { AttributeValue };
+import { CoherencyMode } from "./coherency_mode_type.ts";
+export { CoherencyMode };
+import { Condition } from "./condition_type.ts";
+export { Condition };
+import { ConditionKind } from "./condition_kind_type.ts";
+export { ConditionKind };
+import { EmbarkedUnit } from "./embarked_unit_type.ts";
+export { EmbarkedUnit };
+import { FacingTarget } from "./facing_target_type.ts";
+export { FacingTarget };
+import { Faction } from "./faction_type.ts";
+export { Faction };
+import { FactionMember } from "./faction_member_type.ts";
+export { FactionMember };
+import { Formation } from "./formation_type.ts";
+export { Formation };
 import { Game } from "./game_type.ts";
 export { Game };
 import { GameState } from "./game_state_type.ts";
 export { GameState };
+import { Layer } from "./layer_type.ts";
+export { Layer };
+import { LineOfSight } from "./line_of_sight_type.ts";
+export { LineOfSight };
+import { LosResult } from "./los_result_type.ts";
+export { LosResult };
+import { MeasureTarget } from "./measure_target_type.ts";
+export { MeasureTarget };
+import { Measurement } from "./measurement_type.ts";
+export { Measurement };
 import { Overlay } from "./overlay_type.ts";
 export { Overlay };
+import { PieceEdit } from "./piece_edit_type.ts";
+export { PieceEdit };
+import { PlannedPath } from "./planned_path_type.ts";
+export { PlannedPath };
 import { Position } from "./position_type.ts";
 export { Position };
 import { SelectedUnit } from "./selected_unit_type.ts";
 export { SelectedUnit };
 import { ShapeType } from "./shape_type_type.ts";
 export { ShapeType };
+import { Squad } from "./squad_type.ts";
+export { Squad };
+import { TeamMessage } from "./team_message_type.ts";
+export { TeamMessage };
+import { TemplateAttribute } from "./template_attribute_type.ts";
+export { TemplateAttribute };
+import { TemplateKind } from "./template_kind_type.ts";
+export { TemplateKind };
 import { Terrain } from "./terrain_type.ts";
 export { Terrain };
+import { TerrainKind } from "./terrain_kind_type.ts";
+export { TerrainKind };
+import { TextAlign } from "./text_align_type.ts";
+export { TextAlign };
+import { TextContent } from "./text_content_type.ts";
+export { TextContent };
+import { Transport } from "./transport_type.ts";
+export { Transport };
 import { Underlay } from "./underlay_type.ts";
 export { Underlay };
 import { Unit } from "./unit_type.ts";
 export { Unit };
+import { UnitArcs } from "./unit_arcs_type.ts";
+export { UnitArcs };
+import { UnitAttribute } from "./unit_attribute_type.ts";
+export { UnitAttribute };
+import { UnitTemplate } from "./unit_template_type.ts";
+export { UnitTemplate };
+import { Visibility } from "./visibility_type.ts";
+export { Visibility };
 
 const REMOTE_MODULE = {
   tables: {
@@ -111,38 +301,97 @@ const REMOTE_MODULE = {
       rowType: Action.getTypeScriptAlgebraicType(),
       primaryKey: "id",
     },
+    area_template: {
+      tableName: "area_template",
+      rowType: AreaTemplate.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    faction: {
+      tableName: "faction",
+      rowType: Faction.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
+    faction_member: {
+      tableName: "faction_member",
+      rowType: FactionMember.getTypeScriptAlgebraicType(),
+      primaryKey: "id",
+    },
     games: {
       tableName: "games",
       rowType: Game.getTypeScriptAlgebraicType(),
       primaryKey: "id",
     },
-    overlay: {
-      tableName: "overlay",
-      rowType: Overlay.getTypeScriptAlgebraicType(),
+    my_los_results: {
+      tableName: "my_los_results",
+      rowType: LosResult.getTypeScriptAlgebraicType(),
+    },
+    my_measurements: {
+      tableName: "my_measurements",
+      rowType: Measurement.getTypeScriptAlgebraicType(),
+    },
+    my_team_messages: {
+      tableName: "my_team_messages",
+      rowType: TeamMessage.getTypeScriptAlgebraicType(),
+    },
+    unit_template: {
+      tableName: "unit_template",
+      rowType: UnitTemplate.getTypeScriptAlgebraicType(),
       primaryKey: "id",
     },
-    selected_unit: {
-      tableName: "selected_unit",
+    visible_conditions: {
+      tableName: "visible_conditions",
+      rowType: Condition.getTypeScriptAlgebraicType(),
+    },
+    visible_embarked_units: {
+      tableName: "visible_embarked_units",
+      rowType: EmbarkedUnit.getTypeScriptAlgebraicType(),
+    },
+    visible_overlays: {
+      tableName: "visible_overlays",
+      rowType: Overlay.getTypeScriptAlgebraicType(),
+    },
+    visible_planned_paths: {
+      tableName: "visible_planned_paths",
+      rowType: PlannedPath.getTypeScriptAlgebraicType(),
+    },
+    visible_selected_units: {
+      tableName: "visible_selected_units",
       rowType: SelectedUnit.getTypeScriptAlgebraicType(),
-      primaryKey: "id",
     },
-    terrain: {
-      tableName: "terrain",
+    visible_squads: {
+      tableName: "visible_squads",
+      rowType: Squad.getTypeScriptAlgebraicType(),
+    },
+    visible_terrains: {
+      tableName: "visible_terrains",
       rowType: Terrain.getTypeScriptAlgebraicType(),
-      primaryKey: "id",
     },
-    underlay: {
-      tableName: "underlay",
+    visible_transports: {
+      tableName: "visible_transports",
+      rowType: Transport.getTypeScriptAlgebraicType(),
+    },
+    visible_underlays: {
+      tableName: "visible_underlays",
       rowType: Underlay.getTypeScriptAlgebraicType(),
-      primaryKey: "id",
     },
-    unit: {
-      tableName: "unit",
+    visible_unit_arcs: {
+      tableName: "visible_unit_arcs",
+      rowType: UnitArcs.getTypeScriptAlgebraicType(),
+    },
+    visible_unit_attributes: {
+      tableName: "visible_unit_attributes",
+      rowType: UnitAttribute.getTypeScriptAlgebraicType(),
+    },
+    visible_units: {
+      tableName: "visible_units",
       rowType: Unit.getTypeScriptAlgebraicType(),
-      primaryKey: "id",
     },
   },
   reducers: {
+    add_area_template: {
+      reducerName: "add_area_template",
+      argsType: AddAreaTemplate.getTypeScriptAlgebraicType(),
+    },
     add_overlay: {
       reducerName: "add_overlay",
       argsType: AddOverlay.getTypeScriptAlgebraicType(),
@@ -159,22 +408,82 @@ const REMOTE_MODULE = {
       reducerName: "add_unit",
       argsType: AddUnit.getTypeScriptAlgebraicType(),
     },
+    advance_round: {
+      reducerName: "advance_round",
+      argsType: AdvanceRound.getTypeScriptAlgebraicType(),
+    },
+    apply_area_template: {
+      reducerName: "apply_area_template",
+      argsType: ApplyAreaTemplate.getTypeScriptAlgebraicType(),
+    },
+    apply_condition: {
+      reducerName: "apply_condition",
+      argsType: ApplyCondition.getTypeScriptAlgebraicType(),
+    },
+    assign_player: {
+      reducerName: "assign_player",
+      argsType: AssignPlayer.getTypeScriptAlgebraicType(),
+    },
+    award_points: {
+      reducerName: "award_points",
+      argsType: AwardPoints.getTypeScriptAlgebraicType(),
+    },
     chat_message: {
       reducerName: "chat_message",
       argsType: ChatMessage.getTypeScriptAlgebraicType(),
     },
+    check_facing_arc: {
+      reducerName: "check_facing_arc",
+      argsType: CheckFacingArc.getTypeScriptAlgebraicType(),
+    },
+    check_line_of_sight: {
+      reducerName: "check_line_of_sight",
+      argsType: CheckLineOfSight.getTypeScriptAlgebraicType(),
+    },
+    commit_path: {
+      reducerName: "commit_path",
+      argsType: CommitPath.getTypeScriptAlgebraicType(),
+    },
+    create_faction: {
+      reducerName: "create_faction",
+      argsType: CreateFaction.getTypeScriptAlgebraicType(),
+    },
+    create_squad: {
+      reducerName: "create_squad",
+      argsType: CreateSquad.getTypeScriptAlgebraicType(),
+    },
+    create_unit_template: {
+      reducerName: "create_unit_template",
+      argsType: CreateUnitTemplate.getTypeScriptAlgebraicType(),
+    },
+    decrement_unit_attribute: {
+      reducerName: "decrement_unit_attribute",
+      argsType: DecrementUnitAttribute.getTypeScriptAlgebraicType(),
+    },
     delete_all: {
       reducerName: "delete_all",
       argsType: DeleteAll.getTypeScriptAlgebraicType(),
     },
+    delete_area_template: {
+      reducerName: "delete_area_template",
+      argsType: DeleteAreaTemplate.getTypeScriptAlgebraicType(),
+    },
     delete_at_coordinates: {
       reducerName: "delete_at_coordinates",
       argsType: DeleteAtCoordinates.getTypeScriptAlgebraicType(),
     },
+    delete_faction: {
+      reducerName: "delete_faction",
+      argsType: DeleteFaction.getTypeScriptAlgebraicType(),
+    },
     delete_overlay: {
       reducerName: "delete_overlay",
       argsType: DeleteOverlay.getTypeScriptAlgebraicType(),
     },
+    delete_squad: {
+      reducerName: "delete_squad",
+      argsType: DeleteSquad.getTypeScriptAlgebraicType(),
+    },
     delete_terrain: {
       reducerName: "delete_terrain",
       argsType: DeleteTerrain.getTypeScriptAlgebraicType(),
@@ -187,6 +496,26 @@ const REMOTE_MODULE = {
       reducerName: "delete_unit",
       argsType: DeleteUnit.getTypeScriptAlgebraicType(),
     },
+    delete_unit_attribute: {
+      reducerName: "delete_unit_attribute",
+      argsType: DeleteUnitAttribute.getTypeScriptAlgebraicType(),
+    },
+    delete_unit_template: {
+      reducerName: "delete_unit_template",
+      argsType: DeleteUnitTemplate.getTypeScriptAlgebraicType(),
+    },
+    disembark: {
+      reducerName: "disembark",
+      argsType: Disembark.getTypeScriptAlgebraicType(),
+    },
+    disembark_all: {
+      reducerName: "disembark_all",
+      argsType: DisembarkAll.getTypeScriptAlgebraicType(),
+    },
+    embark: {
+      reducerName: "embark",
+      argsType: Embark.getTypeScriptAlgebraicType(),
+    },
     handle_mouse_event: {
       reducerName: "handle_mouse_event",
       argsType: HandleMouseEvent.getTypeScriptAlgebraicType(),
@@ -199,10 +528,134 @@ const REMOTE_MODULE = {
       reducerName: "identity_disconnected",
       argsType: IdentityDisconnected.getTypeScriptAlgebraicType(),
     },
+    import_roster: {
+      reducerName: "import_roster",
+      argsType: ImportRoster.getTypeScriptAlgebraicType(),
+    },
+    increment_unit_attribute: {
+      reducerName: "increment_unit_attribute",
+      argsType: IncrementUnitAttribute.getTypeScriptAlgebraicType(),
+    },
+    measure: {
+      reducerName: "measure",
+      argsType: Measure.getTypeScriptAlgebraicType(),
+    },
+    migrate_rectangle_geometry: {
+      reducerName: "migrate_rectangle_geometry",
+      argsType: MigrateRectangleGeometry.getTypeScriptAlgebraicType(),
+    },
+    remove_condition: {
+      reducerName: "remove_condition",
+      argsType: RemoveCondition.getTypeScriptAlgebraicType(),
+    },
+    request_path: {
+      reducerName: "request_path",
+      argsType: RequestPath.getTypeScriptAlgebraicType(),
+    },
+    reveal: {
+      reducerName: "reveal",
+      argsType: Reveal.getTypeScriptAlgebraicType(),
+    },
     roll_dice: {
       reducerName: "roll_dice",
       argsType: RollDice.getTypeScriptAlgebraicType(),
     },
+    rotate_unit: {
+      reducerName: "rotate_unit",
+      argsType: RotateUnit.getTypeScriptAlgebraicType(),
+    },
+    send_team_message: {
+      reducerName: "send_team_message",
+      argsType: SendTeamMessage.getTypeScriptAlgebraicType(),
+    },
+    set_coherency_mode: {
+      reducerName: "set_coherency_mode",
+      argsType: SetCoherencyMode.getTypeScriptAlgebraicType(),
+    },
+    set_faction_color: {
+      reducerName: "set_faction_color",
+      argsType: SetFactionColor.getTypeScriptAlgebraicType(),
+    },
+    set_fog_of_war: {
+      reducerName: "set_fog_of_war",
+      argsType: SetFogOfWar.getTypeScriptAlgebraicType(),
+    },
+    set_game_master: {
+      reducerName: "set_game_master",
+      argsType: SetGameMaster.getTypeScriptAlgebraicType(),
+    },
+    set_overlay_text: {
+      reducerName: "set_overlay_text",
+      argsType: SetOverlayText.getTypeScriptAlgebraicType(),
+    },
+    set_squad_rule: {
+      reducerName: "set_squad_rule",
+      argsType: SetSquadRule.getTypeScriptAlgebraicType(),
+    },
+    set_terrain_kind: {
+      reducerName: "set_terrain_kind",
+      argsType: SetTerrainKind.getTypeScriptAlgebraicType(),
+    },
+    set_transport_capacity: {
+      reducerName: "set_transport_capacity",
+      argsType: SetTransportCapacity.getTypeScriptAlgebraicType(),
+    },
+    set_unit_arcs: {
+      reducerName: "set_unit_arcs",
+      argsType: SetUnitArcs.getTypeScriptAlgebraicType(),
+    },
+    set_unit_attribute: {
+      reducerName: "set_unit_attribute",
+      argsType: SetUnitAttribute.getTypeScriptAlgebraicType(),
+    },
+    set_unit_faction: {
+      reducerName: "set_unit_faction",
+      argsType: SetUnitFaction.getTypeScriptAlgebraicType(),
+    },
+    set_unit_squad: {
+      reducerName: "set_unit_squad",
+      argsType: SetUnitSquad.getTypeScriptAlgebraicType(),
+    },
+    set_unit_text: {
+      reducerName: "set_unit_text",
+      argsType: SetUnitText.getTypeScriptAlgebraicType(),
+    },
+    set_visibility: {
+      reducerName: "set_visibility",
+      argsType: SetVisibility.getTypeScriptAlgebraicType(),
+    },
+    set_vision_radius: {
+      reducerName: "set_vision_radius",
+      argsType: SetVisionRadius.getTypeScriptAlgebraicType(),
+    },
+    spawn_from_template: {
+      reducerName: "spawn_from_template",
+      argsType: SpawnFromTemplate.getTypeScriptAlgebraicType(),
+    },
+    toggle_terrain: {
+      reducerName: "toggle_terrain",
+      argsType: ToggleTerrain.getTypeScriptAlgebraicType(),
+    },
+    unassign_player: {
+      reducerName: "unassign_player",
+      argsType: UnassignPlayer.getTypeScriptAlgebraicType(),
+    },
+    update_overlay: {
+      reducerName: "update_overlay",
+      argsType: UpdateOverlay.getTypeScriptAlgebraicType(),
+    },
+    update_terrain: {
+      reducerName: "update_terrain",
+      argsType: UpdateTerrain.getTypeScriptAlgebraicType(),
+    },
+    update_underlay: {
+      reducerName: "update_underlay",
+      argsType: UpdateUnderlay.getTypeScriptAlgebraicType(),
+    },
+    update_unit: {
+      reducerName: "update_unit",
+      argsType: UpdateUnit.getTypeScriptAlgebraicType(),
+    },
   },
   // Constructors which are used by the DbConnectionImpl to
   // extract type information from the generated RemoteModule.
@@ -230,90 +683,238 @@ const REMOTE_MODULE = {
 
 // A type representing all the possible variants of a reducer.
 export type Reducer = never
+| { name: "AddAreaTemplate", args: AddAreaTemplate }
 | { name: "AddOverlay", args: AddOverlay }
 | { name: "AddTerrain", args: AddTerrain }
 | { name: "AddUnderlay", args: AddUnderlay }
 | { name: "AddUnit", args: AddUnit }
+| { name: "AdvanceRound", args: AdvanceRound }
+| { name: "ApplyAreaTemplate", args: ApplyAreaTemplate }
+| { name: "ApplyCondition", args: ApplyCondition }
+| { name: "AssignPlayer", args: AssignPlayer }
+| { name: "AwardPoints", args: AwardPoints }
 | { name: "ChatMessage", args: ChatMessage }
+| { name: "CheckFacingArc", args: CheckFacingArc }
+| { name: "CheckLineOfSight", args: CheckLineOfSight }
+| { name: "CommitPath", args: CommitPath }
+| { name: "CreateFaction", args: CreateFaction }
+| { name: "CreateSquad", args: CreateSquad }
+| { name: "CreateUnitTemplate", args: CreateUnitTemplate }
+| { name: "DecrementUnitAttribute", args: DecrementUnitAttribute }
 | { name: "DeleteAll", args: DeleteAll }
+| { name: "DeleteAreaTemplate", args: DeleteAreaTemplate }
 | { name: "DeleteAtCoordinates", args: DeleteAtCoordinates }
+| { name: "DeleteFaction", args: DeleteFaction }
 | { name: "DeleteOverlay", args: DeleteOverlay }
+| { name: "DeleteSquad", args: DeleteSquad }
 | { name: "DeleteTerrain", args: DeleteTerrain }
 | { name: "DeleteUnderlay", args: DeleteUnderlay }
 | { name: "DeleteUnit", args: DeleteUnit }
+| { name: "DeleteUnitAttribute", args: DeleteUnitAttribute }
+| { name: "DeleteUnitTemplate", args: DeleteUnitTemplate }
+| { name: "Disembark", args: Disembark }
+| { name: "DisembarkAll", args: DisembarkAll }
+| { name: "Embark", args: Embark }
 | { name: "HandleMouseEvent", args: HandleMouseEvent }
 | { name: "IdentityConnected", args: IdentityConnected }
 | { name: "IdentityDisconnected", args: IdentityDisconnected }
+| { name: "ImportRoster", args: ImportRoster }
+| { name: "IncrementUnitAttribute", args: IncrementUnitAttribute }
+| { name: "Measure", args: Measure }
+| { name: "MigrateRectangleGeometry", args: MigrateRectangleGeometry }
+| { name: "RemoveCondition", args: RemoveCondition }
+| { name: "RequestPath", args: RequestPath }
+| { name: "Reveal", args: Reveal }
 | { name: "RollDice", args: RollDice }
+| { name: "RotateUnit", args: RotateUnit }
+| { name: "SendTeamMessage", args: SendTeamMessage }
+| { name: "SetCoherencyMode", args: SetCoherencyMode }
+| { name: "SetFactionColor", args: SetFactionColor }
+| { name: "SetFogOfWar", args: SetFogOfWar }
+| { name: "SetGameMaster", args: SetGameMaster }
+| { name: "SetOverlayText", args: SetOverlayText }
+| { name: "SetSquadRule", args: SetSquadRule }
+| { name: "SetTerrainKind", args: SetTerrainKind }
+| { name: "SetTransportCapacity", args: SetTransportCapacity }
+| { name: "SetUnitArcs", args: SetUnitArcs }
+| { name: "SetUnitAttribute", args: SetUnitAttribute }
+| { name: "SetUnitFaction", args: SetUnitFaction }
+| { name: "SetUnitSquad", args: SetUnitSquad }
+| { name: "SetUnitText", args: SetUnitText }
+| { name: "SetVisibility", args: SetVisibility }
+| { name: "SetVisionRadius", args: SetVisionRadius }
+| { name: "SpawnFromTemplate", args: SpawnFromTemplate }
+| { name: "ToggleTerrain", args: ToggleTerrain }
+| { name: "UnassignPlayer", args: UnassignPlayer }
+| { name: "UpdateOverlay", args: UpdateOverlay }
+| { name: "UpdateTerrain", args: UpdateTerrain }
+| { name: "UpdateUnderlay", args: UpdateUnderlay }
+| { name: "UpdateUnit", args: UpdateUnit }
 ;
 
 export class RemoteReducers {
   constructor(private connection: DbConnectionImpl, private setCallReducerFlags: SetReducerFlags) {}
 
-  addOverlay(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) {
-    const __args = { gameId, shapeType, size, color, position };
+  addAreaTemplate(gameId: bigint, kind: TemplateKind, origin: Position, size: number, width: number, direction: number, color: string, respectLineOfSight: boolean) {
+    const __args = { gameId, kind, origin, size, width, direction, color, respectLineOfSight };
+    let __writer = new BinaryWriter(1024);
+    AddAreaTemplate.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("add_area_template", __argsBuffer, this.setCallReducerFlags.addAreaTemplateFlags);
+  }
+
+  onAddAreaTemplate(callback: (ctx: ReducerEventContext, gameId: bigint, kind: TemplateKind, origin: Position, size: number, width: number, direction: number, color: string, respectLineOfSight: boolean) => void) {
+    this.connection.onReducer("add_area_template", callback);
+  }
+
+  removeOnAddAreaTemplate(callback: (ctx: ReducerEventContext, gameId: bigint, kind: TemplateKind, origin: Position, size: number, width: number, direction: number, color: string, respectLineOfSight: boolean) => void) {
+    this.connection.offReducer("add_area_template", callback);
+  }
+
+  addOverlay(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, visibility: Visibility | undefined) {
+    const __args = { gameId, shapeType, size, color, position, text, visibility };
     let __writer = new BinaryWriter(1024);
     AddOverlay.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("add_overlay", __argsBuffer, this.setCallReducerFlags.addOverlayFlags);
   }
 
-  onAddOverlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) => void) {
+  onAddOverlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, visibility: Visibility | undefined) => void) {
     this.connection.onReducer("add_overlay", callback);
   }
 
-  removeOnAddOverlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) => void) {
+  removeOnAddOverlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, visibility: Visibility | undefined) => void) {
     this.connection.offReducer("add_overlay", callback);
   }
 
-  addTerrain(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], traversable: boolean) {
-    const __args = { gameId, shapeType, size, color, position, traversable };
+  addTerrain(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], traversable: boolean, visibility: Visibility | undefined) {
+    const __args = { gameId, shapeType, size, color, position, traversable, visibility };
     let __writer = new BinaryWriter(1024);
     AddTerrain.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("add_terrain", __argsBuffer, this.setCallReducerFlags.addTerrainFlags);
   }
 
-  onAddTerrain(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], traversable: boolean) => void) {
+  onAddTerrain(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], traversable: boolean, visibility: Visibility | undefined) => void) {
     this.connection.onReducer("add_terrain", callback);
   }
 
-  removeOnAddTerrain(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], traversable: boolean) => void) {
+  removeOnAddTerrain(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], traversable: boolean, visibility: Visibility | undefined) => void) {
     this.connection.offReducer("add_terrain", callback);
   }
 
-  addUnderlay(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) {
-    const __args = { gameId, shapeType, size, color, position };
+  addUnderlay(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], visibility: Visibility | undefined) {
+    const __args = { gameId, shapeType, size, color, position, visibility };
     let __writer = new BinaryWriter(1024);
     AddUnderlay.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("add_underlay", __argsBuffer, this.setCallReducerFlags.addUnderlayFlags);
   }
 
-  onAddUnderlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) => void) {
+  onAddUnderlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], visibility: Visibility | undefined) => void) {
     this.connection.onReducer("add_underlay", callback);
   }
 
-  removeOnAddUnderlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) => void) {
+  removeOnAddUnderlay(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], visibility: Visibility | undefined) => void) {
     this.connection.offReducer("add_underlay", callback);
   }
 
-  addUnit(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) {
-    const __args = { gameId, shapeType, size, color, position };
+  addUnit(gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, visibility: Visibility | undefined) {
+    const __args = { gameId, shapeType, size, color, position, text, visibility };
     let __writer = new BinaryWriter(1024);
     AddUnit.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("add_unit", __argsBuffer, this.setCallReducerFlags.addUnitFlags);
   }
 
-  onAddUnit(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) => void) {
+  onAddUnit(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, visibility: Visibility | undefined) => void) {
     this.connection.onReducer("add_unit", callback);
   }
 
-  removeOnAddUnit(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[]) => void) {
+  removeOnAddUnit(callback: (ctx: ReducerEventContext, gameId: bigint, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, visibility: Visibility | undefined) => void) {
     this.connection.offReducer("add_unit", callback);
   }
 
+  advanceRound(gameId: bigint) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    AdvanceRound.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("advance_round", __argsBuffer, this.setCallReducerFlags.advanceRoundFlags);
+  }
+
+  onAdvanceRound(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.onReducer("advance_round", callback);
+  }
+
+  removeOnAdvanceRound(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.offReducer("advance_round", callback);
+  }
+
+  applyAreaTemplate(templateId: bigint) {
+    const __args = { templateId };
+    let __writer = new BinaryWriter(1024);
+    ApplyAreaTemplate.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("apply_area_template", __argsBuffer, this.setCallReducerFlags.applyAreaTemplateFlags);
+  }
+
+  onApplyAreaTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.onReducer("apply_area_template", callback);
+  }
+
+  removeOnApplyAreaTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.offReducer("apply_area_template", callback);
+  }
+
+  applyCondition(unitId: bigint, kind: ConditionKind, rounds: number | undefined) {
+    const __args = { unitId, kind, rounds };
+    let __writer = new BinaryWriter(1024);
+    ApplyCondition.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("apply_condition", __argsBuffer, this.setCallReducerFlags.applyConditionFlags);
+  }
+
+  onApplyCondition(callback: (ctx: ReducerEventContext, unitId: bigint, kind: ConditionKind, rounds: number | undefined) => void) {
+    this.connection.onReducer("apply_condition", callback);
+  }
+
+  removeOnApplyCondition(callback: (ctx: ReducerEventContext, unitId: bigint, kind: ConditionKind, rounds: number | undefined) => void) {
+    this.connection.offReducer("apply_condition", callback);
+  }
+
+  assignPlayer(factionId: bigint, player: Identity) {
+    const __args = { factionId, player };
+    let __writer = new BinaryWriter(1024);
+    AssignPlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("assign_player", __argsBuffer, this.setCallReducerFlags.assignPlayerFlags);
+  }
+
+  onAssignPlayer(callback: (ctx: ReducerEventContext, factionId: bigint, player: Identity) => void) {
+    this.connection.onReducer("assign_player", callback);
+  }
+
+  removeOnAssignPlayer(callback: (ctx: ReducerEventContext, factionId: bigint, player: Identity) => void) {
+    this.connection.offReducer("assign_player", callback);
+  }
+
+  awardPoints(factionId: bigint, points: number, reason: string) {
+    const __args = { factionId, points, reason };
+    let __writer = new BinaryWriter(1024);
+    AwardPoints.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("award_points", __argsBuffer, this.setCallReducerFlags.awardPointsFlags);
+  }
+
+  onAwardPoints(callback: (ctx: ReducerEventContext, factionId: bigint, points: number, reason: string) => void) {
+    this.connection.onReducer("award_points", callback);
+  }
+
+  removeOnAwardPoints(callback: (ctx: ReducerEventContext, factionId: bigint, points: number, reason: string) => void) {
+    this.connection.offReducer("award_points", callback);
+  }
+
   chatMessage(gameId: bigint, message: string) {
     const __args = { gameId, message };
     let __writer = new BinaryWriter(1024);
@@ -330,6 +931,118 @@ export class RemoteReducers {
     this.connection.offReducer("chat_message", callback);
   }
 
+  checkFacingArc(unitId: bigint, target: FacingTarget) {
+    const __args = { unitId, target };
+    let __writer = new BinaryWriter(1024);
+    CheckFacingArc.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("check_facing_arc", __argsBuffer, this.setCallReducerFlags.checkFacingArcFlags);
+  }
+
+  onCheckFacingArc(callback: (ctx: ReducerEventContext, unitId: bigint, target: FacingTarget) => void) {
+    this.connection.onReducer("check_facing_arc", callback);
+  }
+
+  removeOnCheckFacingArc(callback: (ctx: ReducerEventContext, unitId: bigint, target: FacingTarget) => void) {
+    this.connection.offReducer("check_facing_arc", callback);
+  }
+
+  checkLineOfSight(fromUnitId: bigint, toUnitId: bigint, logResult: boolean) {
+    const __args = { fromUnitId, toUnitId, logResult };
+    let __writer = new BinaryWriter(1024);
+    CheckLineOfSight.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("check_line_of_sight", __argsBuffer, this.setCallReducerFlags.checkLineOfSightFlags);
+  }
+
+  onCheckLineOfSight(callback: (ctx: ReducerEventContext, fromUnitId: bigint, toUnitId: bigint, logResult: boolean) => void) {
+    this.connection.onReducer("check_line_of_sight", callback);
+  }
+
+  removeOnCheckLineOfSight(callback: (ctx: ReducerEventContext, fromUnitId: bigint, toUnitId: bigint, logResult: boolean) => void) {
+    this.connection.offReducer("check_line_of_sight", callback);
+  }
+
+  commitPath(unitId: bigint) {
+    const __args = { unitId };
+    let __writer = new BinaryWriter(1024);
+    CommitPath.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("commit_path", __argsBuffer, this.setCallReducerFlags.commitPathFlags);
+  }
+
+  onCommitPath(callback: (ctx: ReducerEventContext, unitId: bigint) => void) {
+    this.connection.onReducer("commit_path", callback);
+  }
+
+  removeOnCommitPath(callback: (ctx: ReducerEventContext, unitId: bigint) => void) {
+    this.connection.offReducer("commit_path", callback);
+  }
+
+  createFaction(gameId: bigint, name: string, color: string) {
+    const __args = { gameId, name, color };
+    let __writer = new BinaryWriter(1024);
+    CreateFaction.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_faction", __argsBuffer, this.setCallReducerFlags.createFactionFlags);
+  }
+
+  onCreateFaction(callback: (ctx: ReducerEventContext, gameId: bigint, name: string, color: string) => void) {
+    this.connection.onReducer("create_faction", callback);
+  }
+
+  removeOnCreateFaction(callback: (ctx: ReducerEventContext, gameId: bigint, name: string, color: string) => void) {
+    this.connection.offReducer("create_faction", callback);
+  }
+
+  createSquad(gameId: bigint, name: string, distance: number, count: number, unitIds: bigint[]) {
+    const __args = { gameId, name, distance, count, unitIds };
+    let __writer = new BinaryWriter(1024);
+    CreateSquad.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_squad", __argsBuffer, this.setCallReducerFlags.createSquadFlags);
+  }
+
+  onCreateSquad(callback: (ctx: ReducerEventContext, gameId: bigint, name: string, distance: number, count: number, unitIds: bigint[]) => void) {
+    this.connection.onReducer("create_squad", callback);
+  }
+
+  removeOnCreateSquad(callback: (ctx: ReducerEventContext, gameId: bigint, name: string, distance: number, count: number, unitIds: bigint[]) => void) {
+    this.connection.offReducer("create_squad", callback);
+  }
+
+  createUnitTemplate(gameId: bigint | undefined, name: string, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, attributes: TemplateAttribute[]) {
+    const __args = { gameId, name, shapeType, size, color, position, text, attributes };
+    let __writer = new BinaryWriter(1024);
+    CreateUnitTemplate.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("create_unit_template", __argsBuffer, this.setCallReducerFlags.createUnitTemplateFlags);
+  }
+
+  onCreateUnitTemplate(callback: (ctx: ReducerEventContext, gameId: bigint | undefined, name: string, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, attributes: TemplateAttribute[]) => void) {
+    this.connection.onReducer("create_unit_template", callback);
+  }
+
+  removeOnCreateUnitTemplate(callback: (ctx: ReducerEventContext, gameId: bigint | undefined, name: string, shapeType: ShapeType, size: number[], color: string, position: Position[], text: TextContent | undefined, attributes: TemplateAttribute[]) => void) {
+    this.connection.offReducer("create_unit_template", callback);
+  }
+
+  decrementUnitAttribute(unitId: bigint, name: string, amount: number) {
+    const __args = { unitId, name, amount };
+    let __writer = new BinaryWriter(1024);
+    DecrementUnitAttribute.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("decrement_unit_attribute", __argsBuffer, this.setCallReducerFlags.decrementUnitAttributeFlags);
+  }
+
+  onDecrementUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string, amount: number) => void) {
+    this.connection.onReducer("decrement_unit_attribute", callback);
+  }
+
+  removeOnDecrementUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string, amount: number) => void) {
+    this.connection.offReducer("decrement_unit_attribute", callback);
+  }
+
   deleteAll(gameId: bigint) {
     const __args = { gameId };
     let __writer = new BinaryWriter(1024);
@@ -346,22 +1059,54 @@ export class RemoteReducers {
     this.connection.offReducer("delete_all", callback);
   }
 
-  deleteAtCoordinates(gameId: bigint, x: number, y: number) {
-    const __args = { gameId, x, y };
+  deleteAreaTemplate(templateId: bigint) {
+    const __args = { templateId };
+    let __writer = new BinaryWriter(1024);
+    DeleteAreaTemplate.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_area_template", __argsBuffer, this.setCallReducerFlags.deleteAreaTemplateFlags);
+  }
+
+  onDeleteAreaTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.onReducer("delete_area_template", callback);
+  }
+
+  removeOnDeleteAreaTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.offReducer("delete_area_template", callback);
+  }
+
+  deleteAtCoordinates(gameId: bigint, x: number, y: number, layers: Layer[]) {
+    const __args = { gameId, x, y, layers };
     let __writer = new BinaryWriter(1024);
     DeleteAtCoordinates.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
     this.connection.callReducer("delete_at_coordinates", __argsBuffer, this.setCallReducerFlags.deleteAtCoordinatesFlags);
   }
 
-  onDeleteAtCoordinates(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number) => void) {
+  onDeleteAtCoordinates(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number, layers: Layer[]) => void) {
     this.connection.onReducer("delete_at_coordinates", callback);
   }
 
-  removeOnDeleteAtCoordinates(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number) => void) {
+  removeOnDeleteAtCoordinates(callback: (ctx: ReducerEventContext, gameId: bigint, x: number, y: number, layers: Layer[]) => void) {
     this.connection.offReducer("delete_at_coordinates", callback);
   }
 
+  deleteFaction(factionId: bigint) {
+    const __args = { factionId };
+    let __writer = new BinaryWriter(1024);
+    DeleteFaction.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_faction", __argsBuffer, this.setCallReducerFlags.deleteFactionFlags);
+  }
+
+  onDeleteFaction(callback: (ctx: ReducerEventContext, factionId: bigint) => void) {
+    this.connection.onReducer("delete_faction", callback);
+  }
+
+  removeOnDeleteFaction(callback: (ctx: ReducerEventContext, factionId: bigint) => void) {
+    this.connection.offReducer("delete_faction", callback);
+  }
+
   deleteOverlay(overlayId: bigint) {
     const __args = { overlayId };
     let __writer = new BinaryWriter(1024);
@@ -378,6 +1123,22 @@ export class RemoteReducers {
     this.connection.offReducer("delete_overlay", callback);
   }
 
+  deleteSquad(squadId: bigint) {
+    const __args = { squadId };
+    let __writer = new BinaryWriter(1024);
+    DeleteSquad.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_squad", __argsBuffer, this.setCallReducerFlags.deleteSquadFlags);
+  }
+
+  onDeleteSquad(callback: (ctx: ReducerEventContext, squadId: bigint) => void) {
+    this.connection.onReducer("delete_squad", callback);
+  }
+
+  removeOnDeleteSquad(callback: (ctx: ReducerEventContext, squadId: bigint) => void) {
+    this.connection.offReducer("delete_squad", callback);
+  }
+
   deleteTerrain(terrainId: bigint) {
     const __args = { terrainId };
     let __writer = new BinaryWriter(1024);
@@ -426,6 +1187,86 @@ export class RemoteReducers {
     this.connection.offReducer("delete_unit", callback);
   }
 
+  deleteUnitAttribute(unitId: bigint, name: string) {
+    const __args = { unitId, name };
+    let __writer = new BinaryWriter(1024);
+    DeleteUnitAttribute.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_unit_attribute", __argsBuffer, this.setCallReducerFlags.deleteUnitAttributeFlags);
+  }
+
+  onDeleteUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string) => void) {
+    this.connection.onReducer("delete_unit_attribute", callback);
+  }
+
+  removeOnDeleteUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string) => void) {
+    this.connection.offReducer("delete_unit_attribute", callback);
+  }
+
+  deleteUnitTemplate(templateId: bigint) {
+    const __args = { templateId };
+    let __writer = new BinaryWriter(1024);
+    DeleteUnitTemplate.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("delete_unit_template", __argsBuffer, this.setCallReducerFlags.deleteUnitTemplateFlags);
+  }
+
+  onDeleteUnitTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.onReducer("delete_unit_template", callback);
+  }
+
+  removeOnDeleteUnitTemplate(callback: (ctx: ReducerEventContext, templateId: bigint) => void) {
+    this.connection.offReducer("delete_unit_template", callback);
+  }
+
+  disembark(unitId: bigint) {
+    const __args = { unitId };
+    let __writer = new BinaryWriter(1024);
+    Disembark.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("disembark", __argsBuffer, this.setCallReducerFlags.disembarkFlags);
+  }
+
+  onDisembark(callback: (ctx: ReducerEventContext, unitId: bigint) => void) {
+    this.connection.onReducer("disembark", callback);
+  }
+
+  removeOnDisembark(callback: (ctx: ReducerEventContext, unitId: bigint) => void) {
+    this.connection.offReducer("disembark", callback);
+  }
+
+  disembarkAll(carrierId: bigint) {
+    const __args = { carrierId };
+    let __writer = new BinaryWriter(1024);
+    DisembarkAll.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("disembark_all", __argsBuffer, this.setCallReducerFlags.disembarkAllFlags);
+  }
+
+  onDisembarkAll(callback: (ctx: ReducerEventContext, carrierId: bigint) => void) {
+    this.connection.onReducer("disembark_all", callback);
+  }
+
+  removeOnDisembarkAll(callback: (ctx: ReducerEventContext, carrierId: bigint) => void) {
+    this.connection.offReducer("disembark_all", callback);
+  }
+
+  embark(unitId: bigint, carrierId: bigint) {
+    const __args = { unitId, carrierId };
+    let __writer = new BinaryWriter(1024);
+    Embark.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("embark", __argsBuffer, this.setCallReducerFlags.embarkFlags);
+  }
+
+  onEmbark(callback: (ctx: ReducerEventContext, unitId: bigint, carrierId: bigint) => void) {
+    this.connection.onReducer("embark", callback);
+  }
+
+  removeOnEmbark(callback: (ctx: ReducerEventContext, unitId: bigint, carrierId: bigint) => void) {
+    this.connection.offReducer("embark", callback);
+  }
+
   handleMouseEvent(gameId: bigint, eventType: string, x: number, y: number, offsetX: number, offsetY: number) {
     const __args = { gameId, eventType, x, y, offsetX, offsetY };
     let __writer = new BinaryWriter(1024);
@@ -458,25 +1299,526 @@ export class RemoteReducers {
     this.connection.offReducer("identity_disconnected", callback);
   }
 
-  rollDice(gameId: bigint) {
-    const __args = { gameId };
+  importRoster(gameId: bigint, roster: string, zoneMin: Position, zoneMax: Position) {
+    const __args = { gameId, roster, zoneMin, zoneMax };
     let __writer = new BinaryWriter(1024);
-    RollDice.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    ImportRoster.getTypeScriptAlgebraicType().serialize(__writer, __args);
     let __argsBuffer = __writer.getBuffer();
-    this.connection.callReducer("roll_dice", __argsBuffer, this.setCallReducerFlags.rollDiceFlags);
+    this.connection.callReducer("import_roster", __argsBuffer, this.setCallReducerFlags.importRosterFlags);
   }
 
-  onRollDice(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
-    this.connection.onReducer("roll_dice", callback);
+  onImportRoster(callback: (ctx: ReducerEventContext, gameId: bigint, roster: string, zoneMin: Position, zoneMax: Position) => void) {
+    this.connection.onReducer("import_roster", callback);
   }
 
-  removeOnRollDice(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
-    this.connection.offReducer("roll_dice", callback);
+  removeOnImportRoster(callback: (ctx: ReducerEventContext, gameId: bigint, roster: string, zoneMin: Position, zoneMax: Position) => void) {
+    this.connection.offReducer("import_roster", callback);
   }
 
-}
-
+  incrementUnitAttribute(unitId: bigint, name: string, amount: number) {
+    const __args = { unitId, name, amount };
+    let __writer = new BinaryWriter(1024);
+    IncrementUnitAttribute.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("increment_unit_attribute", __argsBuffer, this.setCallReducerFlags.incrementUnitAttributeFlags);
+  }
+
+  onIncrementUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string, amount: number) => void) {
+    this.connection.onReducer("increment_unit_attribute", callback);
+  }
+
+  removeOnIncrementUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string, amount: number) => void) {
+    this.connection.offReducer("increment_unit_attribute", callback);
+  }
+
+  measure(from: MeasureTarget, to: MeasureTarget, logResult: boolean) {
+    const __args = { from, to, logResult };
+    let __writer = new BinaryWriter(1024);
+    Measure.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("measure", __argsBuffer, this.setCallReducerFlags.measureFlags);
+  }
+
+  onMeasure(callback: (ctx: ReducerEventContext, from: MeasureTarget, to: MeasureTarget, logResult: boolean) => void) {
+    this.connection.onReducer("measure", callback);
+  }
+
+  removeOnMeasure(callback: (ctx: ReducerEventContext, from: MeasureTarget, to: MeasureTarget, logResult: boolean) => void) {
+    this.connection.offReducer("measure", callback);
+  }
+
+  migrateRectangleGeometry() {
+    const __args = {  };
+    let __writer = new BinaryWriter(1024);
+    MigrateRectangleGeometry.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("migrate_rectangle_geometry", __argsBuffer, this.setCallReducerFlags.migrateRectangleGeometryFlags);
+  }
+
+  onMigrateRectangleGeometry(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.onReducer("migrate_rectangle_geometry", callback);
+  }
+
+  removeOnMigrateRectangleGeometry(callback: (ctx: ReducerEventContext) => void) {
+    this.connection.offReducer("migrate_rectangle_geometry", callback);
+  }
+
+  removeCondition(unitId: bigint, kind: ConditionKind) {
+    const __args = { unitId, kind };
+    let __writer = new BinaryWriter(1024);
+    RemoveCondition.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("remove_condition", __argsBuffer, this.setCallReducerFlags.removeConditionFlags);
+  }
+
+  onRemoveCondition(callback: (ctx: ReducerEventContext, unitId: bigint, kind: ConditionKind) => void) {
+    this.connection.onReducer("remove_condition", callback);
+  }
+
+  removeOnRemoveCondition(callback: (ctx: ReducerEventContext, unitId: bigint, kind: ConditionKind) => void) {
+    this.connection.offReducer("remove_condition", callback);
+  }
+
+  requestPath(unitId: bigint, target: Position) {
+    const __args = { unitId, target };
+    let __writer = new BinaryWriter(1024);
+    RequestPath.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("request_path", __argsBuffer, this.setCallReducerFlags.requestPathFlags);
+  }
+
+  onRequestPath(callback: (ctx: ReducerEventContext, unitId: bigint, target: Position) => void) {
+    this.connection.onReducer("request_path", callback);
+  }
+
+  removeOnRequestPath(callback: (ctx: ReducerEventContext, unitId: bigint, target: Position) => void) {
+    this.connection.offReducer("request_path", callback);
+  }
+
+  reveal(layer: Layer, id: bigint) {
+    const __args = { layer, id };
+    let __writer = new BinaryWriter(1024);
+    Reveal.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("reveal", __argsBuffer, this.setCallReducerFlags.revealFlags);
+  }
+
+  onReveal(callback: (ctx: ReducerEventContext, layer: Layer, id: bigint) => void) {
+    this.connection.onReducer("reveal", callback);
+  }
+
+  removeOnReveal(callback: (ctx: ReducerEventContext, layer: Layer, id: bigint) => void) {
+    this.connection.offReducer("reveal", callback);
+  }
+
+  rollDice(gameId: bigint) {
+    const __args = { gameId };
+    let __writer = new BinaryWriter(1024);
+    RollDice.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("roll_dice", __argsBuffer, this.setCallReducerFlags.rollDiceFlags);
+  }
+
+  onRollDice(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.onReducer("roll_dice", callback);
+  }
+
+  removeOnRollDice(callback: (ctx: ReducerEventContext, gameId: bigint) => void) {
+    this.connection.offReducer("roll_dice", callback);
+  }
+
+  rotateUnit(unitId: bigint, rotation: number) {
+    const __args = { unitId, rotation };
+    let __writer = new BinaryWriter(1024);
+    RotateUnit.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("rotate_unit", __argsBuffer, this.setCallReducerFlags.rotateUnitFlags);
+  }
+
+  onRotateUnit(callback: (ctx: ReducerEventContext, unitId: bigint, rotation: number) => void) {
+    this.connection.onReducer("rotate_unit", callback);
+  }
+
+  removeOnRotateUnit(callback: (ctx: ReducerEventContext, unitId: bigint, rotation: number) => void) {
+    this.connection.offReducer("rotate_unit", callback);
+  }
+
+  sendTeamMessage(factionId: bigint, message: string) {
+    const __args = { factionId, message };
+    let __writer = new BinaryWriter(1024);
+    SendTeamMessage.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("send_team_message", __argsBuffer, this.setCallReducerFlags.sendTeamMessageFlags);
+  }
+
+  onSendTeamMessage(callback: (ctx: ReducerEventContext, factionId: bigint, message: string) => void) {
+    this.connection.onReducer("send_team_message", callback);
+  }
+
+  removeOnSendTeamMessage(callback: (ctx: ReducerEventContext, factionId: bigint, message: string) => void) {
+    this.connection.offReducer("send_team_message", callback);
+  }
+
+  setCoherencyMode(gameId: bigint, mode: CoherencyMode) {
+    const __args = { gameId, mode };
+    let __writer = new BinaryWriter(1024);
+    SetCoherencyMode.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_coherency_mode", __argsBuffer, this.setCallReducerFlags.setCoherencyModeFlags);
+  }
+
+  onSetCoherencyMode(callback: (ctx: ReducerEventContext, gameId: bigint, mode: CoherencyMode) => void) {
+    this.connection.onReducer("set_coherency_mode", callback);
+  }
+
+  removeOnSetCoherencyMode(callback: (ctx: ReducerEventContext, gameId: bigint, mode: CoherencyMode) => void) {
+    this.connection.offReducer("set_coherency_mode", callback);
+  }
+
+  setFactionColor(factionId: bigint, color: string) {
+    const __args = { factionId, color };
+    let __writer = new BinaryWriter(1024);
+    SetFactionColor.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_faction_color", __argsBuffer, this.setCallReducerFlags.setFactionColorFlags);
+  }
+
+  onSetFactionColor(callback: (ctx: ReducerEventContext, factionId: bigint, color: string) => void) {
+    this.connection.onReducer("set_faction_color", callback);
+  }
+
+  removeOnSetFactionColor(callback: (ctx: ReducerEventContext, factionId: bigint, color: string) => void) {
+    this.connection.offReducer("set_faction_color", callback);
+  }
+
+  setFogOfWar(gameId: bigint, enabled: boolean) {
+    const __args = { gameId, enabled };
+    let __writer = new BinaryWriter(1024);
+    SetFogOfWar.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_fog_of_war", __argsBuffer, this.setCallReducerFlags.setFogOfWarFlags);
+  }
+
+  onSetFogOfWar(callback: (ctx: ReducerEventContext, gameId: bigint, enabled: boolean) => void) {
+    this.connection.onReducer("set_fog_of_war", callback);
+  }
+
+  removeOnSetFogOfWar(callback: (ctx: ReducerEventContext, gameId: bigint, enabled: boolean) => void) {
+    this.connection.offReducer("set_fog_of_war", callback);
+  }
+
+  setGameMaster(gameId: bigint, gm: Identity | undefined) {
+    const __args = { gameId, gm };
+    let __writer = new BinaryWriter(1024);
+    SetGameMaster.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_game_master", __argsBuffer, this.setCallReducerFlags.setGameMasterFlags);
+  }
+
+  onSetGameMaster(callback: (ctx: ReducerEventContext, gameId: bigint, gm: Identity | undefined) => void) {
+    this.connection.onReducer("set_game_master", callback);
+  }
+
+  removeOnSetGameMaster(callback: (ctx: ReducerEventContext, gameId: bigint, gm: Identity | undefined) => void) {
+    this.connection.offReducer("set_game_master", callback);
+  }
+
+  setOverlayText(overlayId: bigint, text: TextContent | undefined) {
+    const __args = { overlayId, text };
+    let __writer = new BinaryWriter(1024);
+    SetOverlayText.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_overlay_text", __argsBuffer, this.setCallReducerFlags.setOverlayTextFlags);
+  }
+
+  onSetOverlayText(callback: (ctx: ReducerEventContext, overlayId: bigint, text: TextContent | undefined) => void) {
+    this.connection.onReducer("set_overlay_text", callback);
+  }
+
+  removeOnSetOverlayText(callback: (ctx: ReducerEventContext, overlayId: bigint, text: TextContent | undefined) => void) {
+    this.connection.offReducer("set_overlay_text", callback);
+  }
+
+  setSquadRule(squadId: bigint, distance: number, count: number) {
+    const __args = { squadId, distance, count };
+    let __writer = new BinaryWriter(1024);
+    SetSquadRule.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_squad_rule", __argsBuffer, this.setCallReducerFlags.setSquadRuleFlags);
+  }
+
+  onSetSquadRule(callback: (ctx: ReducerEventContext, squadId: bigint, distance: number, count: number) => void) {
+    this.connection.onReducer("set_squad_rule", callback);
+  }
+
+  removeOnSetSquadRule(callback: (ctx: ReducerEventContext, squadId: bigint, distance: number, count: number) => void) {
+    this.connection.offReducer("set_squad_rule", callback);
+  }
+
+  setTerrainKind(terrainId: bigint, kind: TerrainKind) {
+    const __args = { terrainId, kind };
+    let __writer = new BinaryWriter(1024);
+    SetTerrainKind.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_terrain_kind", __argsBuffer, this.setCallReducerFlags.setTerrainKindFlags);
+  }
+
+  onSetTerrainKind(callback: (ctx: ReducerEventContext, terrainId: bigint, kind: TerrainKind) => void) {
+    this.connection.onReducer("set_terrain_kind", callback);
+  }
+
+  removeOnSetTerrainKind(callback: (ctx: ReducerEventContext, terrainId: bigint, kind: TerrainKind) => void) {
+    this.connection.offReducer("set_terrain_kind", callback);
+  }
+
+  setTransportCapacity(unitId: bigint, capacity: number) {
+    const __args = { unitId, capacity };
+    let __writer = new BinaryWriter(1024);
+    SetTransportCapacity.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_transport_capacity", __argsBuffer, this.setCallReducerFlags.setTransportCapacityFlags);
+  }
+
+  onSetTransportCapacity(callback: (ctx: ReducerEventContext, unitId: bigint, capacity: number) => void) {
+    this.connection.onReducer("set_transport_capacity", callback);
+  }
+
+  removeOnSetTransportCapacity(callback: (ctx: ReducerEventContext, unitId: bigint, capacity: number) => void) {
+    this.connection.offReducer("set_transport_capacity", callback);
+  }
+
+  setUnitArcs(unitId: bigint, front: number, rear: number) {
+    const __args = { unitId, front, rear };
+    let __writer = new BinaryWriter(1024);
+    SetUnitArcs.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_unit_arcs", __argsBuffer, this.setCallReducerFlags.setUnitArcsFlags);
+  }
+
+  onSetUnitArcs(callback: (ctx: ReducerEventContext, unitId: bigint, front: number, rear: number) => void) {
+    this.connection.onReducer("set_unit_arcs", callback);
+  }
+
+  removeOnSetUnitArcs(callback: (ctx: ReducerEventContext, unitId: bigint, front: number, rear: number) => void) {
+    this.connection.offReducer("set_unit_arcs", callback);
+  }
+
+  setUnitAttribute(unitId: bigint, name: string, value: AttributeValue, max: number | undefined) {
+    const __args = { unitId, name, value, max };
+    let __writer = new BinaryWriter(1024);
+    SetUnitAttribute.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_unit_attribute", __argsBuffer, this.setCallReducerFlags.setUnitAttributeFlags);
+  }
+
+  onSetUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string, value: AttributeValue, max: number | undefined) => void) {
+    this.connection.onReducer("set_unit_attribute", callback);
+  }
+
+  removeOnSetUnitAttribute(callback: (ctx: ReducerEventContext, unitId: bigint, name: string, value: AttributeValue, max: number | undefined) => void) {
+    this.connection.offReducer("set_unit_attribute", callback);
+  }
+
+  setUnitFaction(unitId: bigint, factionId: bigint | undefined) {
+    const __args = { unitId, factionId };
+    let __writer = new BinaryWriter(1024);
+    SetUnitFaction.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_unit_faction", __argsBuffer, this.setCallReducerFlags.setUnitFactionFlags);
+  }
+
+  onSetUnitFaction(callback: (ctx: ReducerEventContext, unitId: bigint, factionId: bigint | undefined) => void) {
+    this.connection.onReducer("set_unit_faction", callback);
+  }
+
+  removeOnSetUnitFaction(callback: (ctx: ReducerEventContext, unitId: bigint, factionId: bigint | undefined) => void) {
+    this.connection.offReducer("set_unit_faction", callback);
+  }
+
+  setUnitSquad(unitId: bigint, squadId: bigint | undefined) {
+    const __args = { unitId, squadId };
+    let __writer = new BinaryWriter(1024);
+    SetUnitSquad.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_unit_squad", __argsBuffer, this.setCallReducerFlags.setUnitSquadFlags);
+  }
+
+  onSetUnitSquad(callback: (ctx: ReducerEventContext, unitId: bigint, squadId: bigint | undefined) => void) {
+    this.connection.onReducer("set_unit_squad", callback);
+  }
+
+  removeOnSetUnitSquad(callback: (ctx: ReducerEventContext, unitId: bigint, squadId: bigint | undefined) => void) {
+    this.connection.offReducer("set_unit_squad", callback);
+  }
+
+  setUnitText(unitId: bigint, text: TextContent | undefined) {
+    const __args = { unitId, text };
+    let __writer = new BinaryWriter(1024);
+    SetUnitText.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_unit_text", __argsBuffer, this.setCallReducerFlags.setUnitTextFlags);
+  }
+
+  onSetUnitText(callback: (ctx: ReducerEventContext, unitId: bigint, text: TextContent | undefined) => void) {
+    this.connection.onReducer("set_unit_text", callback);
+  }
+
+  removeOnSetUnitText(callback: (ctx: ReducerEventContext, unitId: bigint, text: TextContent | undefined) => void) {
+    this.connection.offReducer("set_unit_text", callback);
+  }
+
+  setVisibility(layer: Layer, id: bigint, visibility: Visibility) {
+    const __args = { layer, id, visibility };
+    let __writer = new BinaryWriter(1024);
+    SetVisibility.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_visibility", __argsBuffer, this.setCallReducerFlags.setVisibilityFlags);
+  }
+
+  onSetVisibility(callback: (ctx: ReducerEventContext, layer: Layer, id: bigint, visibility: Visibility) => void) {
+    this.connection.onReducer("set_visibility", callback);
+  }
+
+  removeOnSetVisibility(callback: (ctx: ReducerEventContext, layer: Layer, id: bigint, visibility: Visibility) => void) {
+    this.connection.offReducer("set_visibility", callback);
+  }
+
+  setVisionRadius(unitId: bigint, radius: number) {
+    const __args = { unitId, radius };
+    let __writer = new BinaryWriter(1024);
+    SetVisionRadius.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("set_vision_radius", __argsBuffer, this.setCallReducerFlags.setVisionRadiusFlags);
+  }
+
+  onSetVisionRadius(callback: (ctx: ReducerEventContext, unitId: bigint, radius: number) => void) {
+    this.connection.onReducer("set_vision_radius", callback);
+  }
+
+  removeOnSetVisionRadius(callback: (ctx: ReducerEventContext, unitId: bigint, radius: number) => void) {
+    this.connection.offReducer("set_vision_radius", callback);
+  }
+
+  spawnFromTemplate(gameId: bigint, templateId: bigint, position: Position, count: number, formation: Formation) {
+    const __args = { gameId, templateId, position, count, formation };
+    let __writer = new BinaryWriter(1024);
+    SpawnFromTemplate.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("spawn_from_template", __argsBuffer, this.setCallReducerFlags.spawnFromTemplateFlags);
+  }
+
+  onSpawnFromTemplate(callback: (ctx: ReducerEventContext, gameId: bigint, templateId: bigint, position: Position, count: number, formation: Formation) => void) {
+    this.connection.onReducer("spawn_from_template", callback);
+  }
+
+  removeOnSpawnFromTemplate(callback: (ctx: ReducerEventContext, gameId: bigint, templateId: bigint, position: Position, count: number, formation: Formation) => void) {
+    this.connection.offReducer("spawn_from_template", callback);
+  }
+
+  toggleTerrain(terrainId: bigint) {
+    const __args = { terrainId };
+    let __writer = new BinaryWriter(1024);
+    ToggleTerrain.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("toggle_terrain", __argsBuffer, this.setCallReducerFlags.toggleTerrainFlags);
+  }
+
+  onToggleTerrain(callback: (ctx: ReducerEventContext, terrainId: bigint) => void) {
+    this.connection.onReducer("toggle_terrain", callback);
+  }
+
+  removeOnToggleTerrain(callback: (ctx: ReducerEventContext, terrainId: bigint) => void) {
+    this.connection.offReducer("toggle_terrain", callback);
+  }
+
+  unassignPlayer(gameId: bigint, player: Identity) {
+    const __args = { gameId, player };
+    let __writer = new BinaryWriter(1024);
+    UnassignPlayer.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("unassign_player", __argsBuffer, this.setCallReducerFlags.unassignPlayerFlags);
+  }
+
+  onUnassignPlayer(callback: (ctx: ReducerEventContext, gameId: bigint, player: Identity) => void) {
+    this.connection.onReducer("unassign_player", callback);
+  }
+
+  removeOnUnassignPlayer(callback: (ctx: ReducerEventContext, gameId: bigint, player: Identity) => void) {
+    this.connection.offReducer("unassign_player", callback);
+  }
+
+  updateOverlay(overlayId: bigint, edit: PieceEdit) {
+    const __args = { overlayId, edit };
+    let __writer = new BinaryWriter(1024);
+    UpdateOverlay.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_overlay", __argsBuffer, this.setCallReducerFlags.updateOverlayFlags);
+  }
+
+  onUpdateOverlay(callback: (ctx: ReducerEventContext, overlayId: bigint, edit: PieceEdit) => void) {
+    this.connection.onReducer("update_overlay", callback);
+  }
+
+  removeOnUpdateOverlay(callback: (ctx: ReducerEventContext, overlayId: bigint, edit: PieceEdit) => void) {
+    this.connection.offReducer("update_overlay", callback);
+  }
+
+  updateTerrain(terrainId: bigint, edit: PieceEdit, traversable: boolean | undefined) {
+    const __args = { terrainId, edit, traversable };
+    let __writer = new BinaryWriter(1024);
+    UpdateTerrain.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_terrain", __argsBuffer, this.setCallReducerFlags.updateTerrainFlags);
+  }
+
+  onUpdateTerrain(callback: (ctx: ReducerEventContext, terrainId: bigint, edit: PieceEdit, traversable: boolean | undefined) => void) {
+    this.connection.onReducer("update_terrain", callback);
+  }
+
+  removeOnUpdateTerrain(callback: (ctx: ReducerEventContext, terrainId: bigint, edit: PieceEdit, traversable: boolean | undefined) => void) {
+    this.connection.offReducer("update_terrain", callback);
+  }
+
+  updateUnderlay(underlayId: bigint, edit: PieceEdit) {
+    const __args = { underlayId, edit };
+    let __writer = new BinaryWriter(1024);
+    UpdateUnderlay.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_underlay", __argsBuffer, this.setCallReducerFlags.updateUnderlayFlags);
+  }
+
+  onUpdateUnderlay(callback: (ctx: ReducerEventContext, underlayId: bigint, edit: PieceEdit) => void) {
+    this.connection.onReducer("update_underlay", callback);
+  }
+
+  removeOnUpdateUnderlay(callback: (ctx: ReducerEventContext, underlayId: bigint, edit: PieceEdit) => void) {
+    this.connection.offReducer("update_underlay", callback);
+  }
+
+  updateUnit(unitId: bigint, edit: PieceEdit) {
+    const __args = { unitId, edit };
+    let __writer = new BinaryWriter(1024);
+    UpdateUnit.getTypeScriptAlgebraicType().serialize(__writer, __args);
+    let __argsBuffer = __writer.getBuffer();
+    this.connection.callReducer("update_unit", __argsBuffer, this.setCallReducerFlags.updateUnitFlags);
+  }
+
+  onUpdateUnit(callback: (ctx: ReducerEventContext, unitId: bigint, edit: PieceEdit) => void) {
+    this.connection.onReducer("update_unit", callback);
+  }
+
+  removeOnUpdateUnit(callback: (ctx: ReducerEventContext, unitId: bigint, edit: PieceEdit) => void) {
+    this.connection.offReducer("update_unit", callback);
+  }
+
+}
+
 export class SetReducerFlags {
+  addAreaTemplateFlags: CallReducerFlags = 'FullUpdate';
+  addAreaTemplate(flags: CallReducerFlags) {
+    this.addAreaTemplateFlags = flags;
+  }
+
   addOverlayFlags: CallReducerFlags = 'FullUpdate';
   addOverlay(flags: CallReducerFlags) {
     this.addOverlayFlags = flags;
@@ -497,26 +1839,101 @@ export class SetReducerFlags {
     this.addUnitFlags = flags;
   }
 
+  advanceRoundFlags: CallReducerFlags = 'FullUpdate';
+  advanceRound(flags: CallReducerFlags) {
+    this.advanceRoundFlags = flags;
+  }
+
+  applyAreaTemplateFlags: CallReducerFlags = 'FullUpdate';
+  applyAreaTemplate(flags: CallReducerFlags) {
+    this.applyAreaTemplateFlags = flags;
+  }
+
+  applyConditionFlags: CallReducerFlags = 'FullUpdate';
+  applyCondition(flags: CallReducerFlags) {
+    this.applyConditionFlags = flags;
+  }
+
+  assignPlayerFlags: CallReducerFlags = 'FullUpdate';
+  assignPlayer(flags: CallReducerFlags) {
+    this.assignPlayerFlags = flags;
+  }
+
+  awardPointsFlags: CallReducerFlags = 'FullUpdate';
+  awardPoints(flags: CallReducerFlags) {
+    this.awardPointsFlags = flags;
+  }
+
   chatMessageFlags: CallReducerFlags = 'FullUpdate';
   chatMessage(flags: CallReducerFlags) {
     this.chatMessageFlags = flags;
   }
 
+  checkFacingArcFlags: CallReducerFlags = 'FullUpdate';
+  checkFacingArc(flags: CallReducerFlags) {
+    this.checkFacingArcFlags = flags;
+  }
+
+  checkLineOfSightFlags: CallReducerFlags = 'FullUpdate';
+  checkLineOfSight(flags: CallReducerFlags) {
+    this.checkLineOfSightFlags = flags;
+  }
+
+  commitPathFlags: CallReducerFlags = 'FullUpdate';
+  commitPath(flags: CallReducerFlags) {
+    this.commitPathFlags = flags;
+  }
+
+  createFactionFlags: CallReducerFlags = 'FullUpdate';
+  createFaction(flags: CallReducerFlags) {
+    this.createFactionFlags = flags;
+  }
+
+  createSquadFlags: CallReducerFlags = 'FullUpdate';
+  createSquad(flags: CallReducerFlags) {
+    this.createSquadFlags = flags;
+  }
+
+  createUnitTemplateFlags: CallReducerFlags = 'FullUpdate';
+  createUnitTemplate(flags: CallReducerFlags) {
+    this.createUnitTemplateFlags = flags;
+  }
+
+  decrementUnitAttributeFlags: CallReducerFlags = 'FullUpdate';
+  decrementUnitAttribute(flags: CallReducerFlags) {
+    this.decrementUnitAttributeFlags = flags;
+  }
+
   deleteAllFlags: CallReducerFlags = 'FullUpdate';
   deleteAll(flags: CallReducerFlags) {
     this.deleteAllFlags = flags;
   }
 
+  deleteAreaTemplateFlags: CallReducerFlags = 'FullUpdate';
+  deleteAreaTemplate(flags: CallReducerFlags) {
+    this.deleteAreaTemplateFlags = flags;
+  }
+
   deleteAtCoordinatesFlags: CallReducerFlags = 'FullUpdate';
   deleteAtCoordinates(flags: CallReducerFlags) {
     this.deleteAtCoordinatesFlags = flags;
   }
 
+  deleteFactionFlags: CallReducerFlags = 'FullUpdate';
+  deleteFaction(flags: CallReducerFlags) {
+    this.deleteFactionFlags = flags;
+  }
+
   deleteOverlayFlags: CallReducerFlags = 'FullUpdate';
   deleteOverlay(flags: CallReducerFlags) {
     this.deleteOverlayFlags = flags;
   }
 
+  deleteSquadFlags: CallReducerFlags = 'FullUpdate';
+  deleteSquad(flags: CallReducerFlags) {
+    this.deleteSquadFlags = flags;
+  }
+
   deleteTerrainFlags: CallReducerFlags = 'FullUpdate';
   deleteTerrain(flags: CallReducerFlags) {
     this.deleteTerrainFlags = flags;
@@ -532,16 +1949,196 @@ export class SetReducerFlags {
     this.deleteUnitFlags = flags;
   }
 
+  deleteUnitAttributeFlags: CallReducerFlags = 'FullUpdate';
+  deleteUnitAttribute(flags: CallReducerFlags) {
+    this.deleteUnitAttributeFlags = flags;
+  }
+
+  deleteUnitTemplateFlags: CallReducerFlags = 'FullUpdate';
+  deleteUnitTemplate(flags: CallReducerFlags) {
+    this.deleteUnitTemplateFlags = flags;
+  }
+
+  disembarkFlags: CallReducerFlags = 'FullUpdate';
+  disembark(flags: CallReducerFlags) {
+    this.disembarkFlags = flags;
+  }
+
+  disembarkAllFlags: CallReducerFlags = 'FullUpdate';
+  disembarkAll(flags: CallReducerFlags) {
+    this.disembarkAllFlags = flags;
+  }
+
+  embarkFlags: CallReducerFlags = 'FullUpdate';
+  embark(flags: CallReducerFlags) {
+    this.embarkFlags = flags;
+  }
+
   handleMouseEventFlags: CallReducerFlags = 'FullUpdate';
   handleMouseEvent(flags: CallReducerFlags) {
     this.handleMouseEventFlags = flags;
   }
 
+  importRosterFlags: CallReducerFlags = 'FullUpdate';
+  importRoster(flags: CallReducerFlags) {
+    this.importRosterFlags = flags;
+  }
+
+  incrementUnitAttributeFlags: CallReducerFlags = 'FullUpdate';
+  incrementUnitAttribute(flags: CallReducerFlags) {
+    this.incrementUnitAttributeFlags = flags;
+  }
+
+  measureFlags: CallReducerFlags = 'FullUpdate';
+  measure(flags: CallReducerFlags) {
+    this.measureFlags = flags;
+  }
+
+  migrateRectangleGeometryFlags: CallReducerFlags = 'FullUpdate';
+  migrateRectangleGeometry(flags: CallReducerFlags) {
+    this.migrateRectangleGeometryFlags = flags;
+  }
+
+  removeConditionFlags: CallReducerFlags = 'FullUpdate';
+  removeCondition(flags: CallReducerFlags) {
+    this.removeConditionFlags = flags;
+  }
+
+  requestPathFlags: CallReducerFlags = 'FullUpdate';
+  requestPath(flags: CallReducerFlags) {
+    this.requestPathFlags = flags;
+  }
+
+  revealFlags: CallReducerFlags = 'FullUpdate';
+  reveal(flags: CallReducerFlags) {
+    this.revealFlags = flags;
+  }
+
   rollDiceFlags: CallReducerFlags = 'FullUpdate';
   rollDice(flags: CallReducerFlags) {
     this.rollDiceFlags = flags;
   }
 
+  rotateUnitFlags: CallReducerFlags = 'FullUpdate';
+  rotateUnit(flags: CallReducerFlags) {
+    this.rotateUnitFlags = flags;
+  }
+
+  sendTeamMessageFlags: CallReducerFlags = 'FullUpdate';
+  sendTeamMessage(flags: CallReducerFlags) {
+    this.sendTeamMessageFlags = flags;
+  }
+
+  setCoherencyModeFlags: CallReducerFlags = 'FullUpdate';
+  setCoherencyMode(flags: CallReducerFlags) {
+    this.setCoherencyModeFlags = flags;
+  }
+
+  setFactionColorFlags: CallReducerFlags = 'FullUpdate';
+  setFactionColor(flags: CallReducerFlags) {
+    this.setFactionColorFlags = flags;
+  }
+
+  setFogOfWarFlags: CallReducerFlags = 'FullUpdate';
+  setFogOfWar(flags: CallReducerFlags) {
+    this.setFogOfWarFlags = flags;
+  }
+
+  setGameMasterFlags: CallReducerFlags = 'FullUpdate';
+  setGameMaster(flags: CallReducerFlags) {
+    this.setGameMasterFlags = flags;
+  }
+
+  setOverlayTextFlags: CallReducerFlags = 'FullUpdate';
+  setOverlayText(flags: CallReducerFlags) {
+    this.setOverlayTextFlags = flags;
+  }
+
+  setSquadRuleFlags: CallReducerFlags = 'FullUpdate';
+  setSquadRule(flags: CallReducerFlags) {
+    this.setSquadRuleFlags = flags;
+  }
+
+  setTerrainKindFlags: CallReducerFlags = 'FullUpdate';
+  setTerrainKind(flags: CallReducerFlags) {
+    this.setTerrainKindFlags = flags;
+  }
+
+  setTransportCapacityFlags: CallReducerFlags = 'FullUpdate';
+  setTransportCapacity(flags: CallReducerFlags) {
+    this.setTransportCapacityFlags = flags;
+  }
+
+  setUnitArcsFlags: CallReducerFlags = 'FullUpdate';
+  setUnitArcs(flags: CallReducerFlags) {
+    this.setUnitArcsFlags = flags;
+  }
+
+  setUnitAttributeFlags: CallReducerFlags = 'FullUpdate';
+  setUnitAttribute(flags: CallReducerFlags) {
+    this.setUnitAttributeFlags = flags;
+  }
+
+  setUnitFactionFlags: CallReducerFlags = 'FullUpdate';
+  setUnitFaction(flags: CallReducerFlags) {
+    this.setUnitFactionFlags = flags;
+  }
+
+  setUnitSquadFlags: CallReducerFlags = 'FullUpdate';
+  setUnitSquad(flags: CallReducerFlags) {
+    this.setUnitSquadFlags = flags;
+  }
+
+  setUnitTextFlags: CallReducerFlags = 'FullUpdate';
+  setUnitText(flags: CallReducerFlags) {
+    this.setUnitTextFlags = flags;
+  }
+
+  setVisibilityFlags: CallReducerFlags = 'FullUpdate';
+  setVisibility(flags: CallReducerFlags) {
+    this.setVisibilityFlags = flags;
+  }
+
+  setVisionRadiusFlags: CallReducerFlags = 'FullUpdate';
+  setVisionRadius(flags: CallReducerFlags) {
+    this.setVisionRadiusFlags = flags;
+  }
+
+  spawnFromTemplateFlags: CallReducerFlags = 'FullUpdate';
+  spawnFromTemplate(flags: CallReducerFlags) {
+    this.spawnFromTemplateFlags = flags;
+  }
+
+  toggleTerrainFlags: CallReducerFlags = 'FullUpdate';
+  toggleTerrain(flags: CallReducerFlags) {
+    this.toggleTerrainFlags = flags;
+  }
+
+  unassignPlayerFlags: CallReducerFlags = 'FullUpdate';
+  unassignPlayer(flags: CallReducerFlags) {
+    this.unassignPlayerFlags = flags;
+  }
+
+  updateOverlayFlags: CallReducerFlags = 'FullUpdate';
+  updateOverlay(flags: CallReducerFlags) {
+    this.updateOverlayFlags = flags;
+  }
+
+  updateTerrainFlags: CallReducerFlags = 'FullUpdate';
+  updateTerrain(flags: CallReducerFlags) {
+    this.updateTerrainFlags = flags;
+  }
+
+  updateUnderlayFlags: CallReducerFlags = 'FullUpdate';
+  updateUnderlay(flags: CallReducerFlags) {
+    this.updateUnderlayFlags = flags;
+  }
+
+  updateUnitFlags: CallReducerFlags = 'FullUpdate';
+  updateUnit(flags: CallReducerFlags) {
+    this.updateUnitFlags = flags;
+  }
+
 }
 
 export class RemoteTables {
@@ -551,28 +2148,84 @@ export class RemoteTables {
     return new ActionTableHandle(this.connection.clientCache.getOrCreateTable<Action>(REMOTE_MODULE.tables.action));
   }
 
+  get areaTemplate(): AreaTemplateTableHandle {
+    return new AreaTemplateTableHandle(this.connection.clientCache.getOrCreateTable<AreaTemplate>(REMOTE_MODULE.tables.area_template));
+  }
+
+  get faction(): FactionTableHandle {
+    return new FactionTableHandle(this.connection.clientCache.getOrCreateTable<Faction>(REMOTE_MODULE.tables.faction));
+  }
+
+  get factionMember(): FactionMemberTableHandle {
+    return new FactionMemberTableHandle(this.connection.clientCache.getOrCreateTable<FactionMember>(REMOTE_MODULE.tables.faction_member));
+  }
+
   get games(): GamesTableHandle {
     return new GamesTableHandle(this.connection.clientCache.getOrCreateTable<Game>(REMOTE_MODULE.tables.games));
   }
 
-  get overlay(): OverlayTableHandle {
-    return new OverlayTableHandle(this.connection.clientCache.getOrCreateTable<Overlay>(REMOTE_MODULE.tables.overlay));
+  get myLosResults(): MyLosResultsTableHandle {
+    return new MyLosResultsTableHandle(this.connection.clientCache.getOrCreateTable<LosResult>(REMOTE_MODULE.tables.my_los_results));
+  }
+
+  get myMeasurements(): MyMeasurementsTableHandle {
+    return new MyMeasurementsTableHandle(this.connection.clientCache.getOrCreateTable<Measurement>(REMOTE_MODULE.tables.my_measurements));
+  }
+
+  get myTeamMessages(): MyTeamMessagesTableHandle {
+    return new MyTeamMessagesTableHandle(this.connection.clientCache.getOrCreateTable<TeamMessage>(REMOTE_MODULE.tables.my_team_messages));
+  }
+
+  get unitTemplate(): UnitTemplateTableHandle {
+    return new UnitTemplateTableHandle(this.connection.clientCache.getOrCreateTable<UnitTemplate>(REMOTE_MODULE.tables.unit_template));
+  }
+
+  get visibleConditions(): VisibleConditionsTableHandle {
+    return new VisibleConditionsTableHandle(this.connection.clientCache.getOrCreateTable<Condition>(REMOTE_MODULE.tables.visible_conditions));
+  }
+
+  get visibleEmbarkedUnits(): VisibleEmbarkedUnitsTableHandle {
+    return new VisibleEmbarkedUnitsTableHandle(this.connection.clientCache.getOrCreateTable<EmbarkedUnit>(REMOTE_MODULE.tables.visible_embarked_units));
+  }
+
+  get visibleOverlays(): VisibleOverlaysTableHandle {
+    return new VisibleOverlaysTableHandle(this.connection.clientCache.getOrCreateTable<Overlay>(REMOTE_MODULE.tables.visible_overlays));
+  }
+
+  get visiblePlannedPaths(): VisiblePlannedPathsTableHandle {
+    return new VisiblePlannedPathsTableHandle(this.connection.clientCache.getOrCreateTable<PlannedPath>(REMOTE_MODULE.tables.visible_planned_paths));
+  }
+
+  get visibleSelectedUnits(): VisibleSelectedUnitsTableHandle {
+    return new VisibleSelectedUnitsTableHandle(this.connection.clientCache.getOrCreateTable<SelectedUnit>(REMOTE_MODULE.tables.visible_selected_units));
+  }
+
+  get visibleSquads(): VisibleSquadsTableHandle {
+    return new VisibleSquadsTableHandle(this.connection.clientCache.getOrCreateTable<Squad>(REMOTE_MODULE.tables.visible_squads));
+  }
+
+  get visibleTerrains(): VisibleTerrainsTableHandle {
+    return new VisibleTerrainsTableHandle(this.connection.clientCache.getOrCreateTable<Terrain>(REMOTE_MODULE.tables.visible_terrains));
+  }
+
+  get visibleTransports(): VisibleTransportsTableHandle {
+    return new VisibleTransportsTableHandle(this.connection.clientCache.getOrCreateTable<Transport>(REMOTE_MODULE.tables.visible_transports));
   }
 
-  get selectedUnit(): SelectedUnitTableHandle {
-    return new SelectedUnitTableHandle(this.connection.clientCache.getOrCreateTable<SelectedUnit>(REMOTE_MODULE.tables.selected_unit));
+  get visibleUnderlays(): VisibleUnderlaysTableHandle {
+    return new VisibleUnderlaysTableHandle(this.connection.clientCache.getOrCreateTable<Underlay>(REMOTE_MODULE.tables.visible_underlays));
   }
 
-  get terrain(): TerrainTableHandle {
-    return new TerrainTableHandle(this.connection.clientCache.getOrCreateTable<Terrain>(REMOTE_MODULE.tables.terrain));
+  get visibleUnitArcs(): VisibleUnitArcsTableHandle {
+    return new VisibleUnitArcsTableHandle(this.connection.clientCache.getOrCreateTable<UnitArcs>(REMOTE_MODULE.tables.visible_unit_arcs));
   }
 
-  get underlay(): UnderlayTableHandle {
-    return new UnderlayTableHandle(this.connection.clientCache.getOrCreateTable<Underlay>(REMOTE_MODULE.tables.underlay));
+  get visibleUnitAttributes(): VisibleUnitAttributesTableHandle {
+    return new VisibleUnitAttributesTableHandle(this.connection.clientCache.getOrCreateTable<UnitAttribute>(REMOTE_MODULE.tables.visible_unit_attributes));
   }
 
-  get unit(): UnitTableHandle {
-    return new UnitTableHandle(this.connection.clientCache.getOrCreateTable<Unit>(REMOTE_MODULE.tables.unit));
+  get visibleUnits(): VisibleUnitsTableHandle {
+    return new VisibleUnitsTableHandle(this.connection.clientCache.getOrCreateTable<Unit>(REMOTE_MODULE.tables.visible_units));
   }
 }
 
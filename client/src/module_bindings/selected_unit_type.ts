@@ -33,13 +33,18 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { Position as __Position } from "./position_type";
+
 export type SelectedUnit = {
   id: bigint,
   gameId: bigint,
+  selector: Identity,
   startX: number,
   startY: number,
   offsetX: number,
   offsetY: number,
+  moved: boolean,
+  startPosition: __Position[],
 };
 
 /**
@@ -54,10 +59,13 @@ export namespace SelectedUnit {
     return AlgebraicType.createProductType([
       new ProductTypeElement("id", AlgebraicType.createU64Type()),
       new ProductTypeElement("gameId", AlgebraicType.createU64Type()),
+      new ProductTypeElement("selector", AlgebraicType.createIdentityType()),
       new ProductTypeElement("startX", AlgebraicType.createU32Type()),
       new ProductTypeElement("startY", AlgebraicType.createU32Type()),
       new ProductTypeElement("offsetX", AlgebraicType.createU32Type()),
       new ProductTypeElement("offsetY", AlgebraicType.createU32Type()),
+      new ProductTypeElement("moved", AlgebraicType.createBoolType()),
+      new ProductTypeElement("startPosition", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
     ]);
   }
 
@@ -23,8 +23,6 @@ import {
   Timestamp,
   deepEqual,
 } from "@clockworklabs/spacetimedb-sdk";
-import { ShapeType as __ShapeType } from "./shape_type_type";
-import { Position as __Position } from "./position_type";
 
 import type {
   CallReducerFlags,
@@ -35,6 +33,11 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+import { ShapeType as __ShapeType } from "./shape_type_type";
+import { Position as __Position } from "./position_type";
+import { Visibility as __Visibility } from "./visibility_type";
+import { TerrainKind as __TerrainKind } from "./terrain_kind_type";
+
 export type Terrain = {
   id: bigint,
   gameId: bigint,
@@ -42,7 +45,12 @@ export type Terrain = {
   size: number[],
   color: string,
   position: __Position[],
+  rotation: number,
+  zOrder: number,
+  owner: Identity | undefined,
+  visibility: __Visibility,
   traversable: boolean,
+  kind: __TerrainKind,
 };
 
 /**
@@ -61,7 +69,12 @@ export namespace Terrain {
       new ProductTypeElement("size", AlgebraicType.createArrayType(AlgebraicType.createU32Type())),
       new ProductTypeElement("color", AlgebraicType.createStringType()),
       new ProductTypeElement("position", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("rotation", AlgebraicType.createF32Type()),
+      new ProductTypeElement("zOrder", AlgebraicType.createI32Type()),
+      new ProductTypeElement("owner", AlgebraicType.createOptionType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("visibility", __Visibility.getTypeScriptAlgebraicType()),
       new ProductTypeElement("traversable", AlgebraicType.createBoolType()),
+      new ProductTypeElement("kind", __TerrainKind.getTypeScriptAlgebraicType()),
     ]);
   }
 
@@ -35,6 +35,7 @@ import type {
 } from "@clockworklabs/spacetimedb-sdk";
 import { ShapeType as __ShapeType } from "./shape_type_type";
 import { Position as __Position } from "./position_type";
+import { Visibility as __Visibility } from "./visibility_type";
 
 export type Underlay = {
   id: bigint,
@@ -43,6 +44,10 @@ export type Underlay = {
   size: number[],
   color: string,
   position: __Position[],
+  rotation: number,
+  zOrder: number,
+  owner: Identity | undefined,
+  visibility: __Visibility,
 };
 
 /**
@@ -61,6 +66,10 @@ export namespace Underlay {
       new ProductTypeElement("size", AlgebraicType.createArrayType(AlgebraicType.createU32Type())),
       new ProductTypeElement("color", AlgebraicType.createStringType()),
       new ProductTypeElement("position", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("rotation", AlgebraicType.createF32Type()),
+      new ProductTypeElement("zOrder", AlgebraicType.createI32Type()),
+      new ProductTypeElement("owner", AlgebraicType.createOptionType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("visibility", __Visibility.getTypeScriptAlgebraicType()),
     ]);
   }
 
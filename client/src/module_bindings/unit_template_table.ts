@@ -33,26 +33,29 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
-import { Underlay } from "./underlay_type";
+
+import { UnitTemplate } from "./unit_template_type";
 import { ShapeType as __ShapeType } from "./shape_type_type";
 import { Position as __Position } from "./position_type";
+import { TextContent as __TextContent } from "./text_content_type";
+import { TemplateAttribute as __TemplateAttribute } from "./template_attribute_type";
 
 import type { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `underlay`.
+ * Table handle for the table `unit_template`.
  *
- * Obtain a handle from the [`underlay`] property on [`RemoteTables`],
- * like `ctx.db.underlay`.
+ * Obtain a handle from the [`unitTemplate`] property on [`RemoteTables`],
+ * like `ctx.db.unitTemplate`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.underlay.on_insert(...)`.
+ * like `ctx.db.unitTemplate.on_insert(...)`.
  */
-export class UnderlayTableHandle {
-  tableCache: TableCache<Underlay>;
+export class UnitTemplateTableHandle {
+  tableCache: TableCache<UnitTemplate>;
 
-  constructor(tableCache: TableCache<Underlay>) {
+  constructor(tableCache: TableCache<UnitTemplate>) {
     this.tableCache = tableCache;
   }
 
@@ -60,24 +63,24 @@ export class UnderlayTableHandle {
     return this.tableCache.count();
   }
 
-  iter(): Iterable<Underlay> {
+  iter(): Iterable<UnitTemplate> {
     return this.tableCache.iter();
   }
   /**
-   * Access to the `id` unique index on the table `underlay`,
+   * Access to the `id` unique index on the table `unit_template`,
    * which allows point queries on the field of the same name
-   * via the [`UnderlayIdUnique.find`] method.
+   * via the [`UnitTemplateIdUnique.find`] method.
    *
    * Users are encouraged not to explicitly reference this type,
    * but to directly chain method calls,
-   * like `ctx.db.underlay.id().find(...)`.
+   * like `ctx.db.unitTemplate.id().find(...)`.
    *
-   * Get a handle on the `id` unique index on the table `underlay`.
+   * Get a handle on the `id` unique index on the table `unit_template`.
    */
   id = {
     // Find the subscribed row whose `id` column value is equal to `col_val`,
     // if such a row is present in the client cache.
-    find: (col_val: bigint): Underlay | undefined => {
+    find: (col_val: bigint): UnitTemplate | undefined => {
       for (let row of this.tableCache.iter()) {
         if (deepEqual(row.id, col_val)) {
           return row;
@@ -86,27 +89,27 @@ export class UnderlayTableHandle {
     },
   };
 
-  onInsert = (cb: (ctx: EventContext, row: Underlay) => void) => {
+  onInsert = (cb: (ctx: EventContext, row: UnitTemplate) => void) => {
     return this.tableCache.onInsert(cb);
   }
 
-  removeOnInsert = (cb: (ctx: EventContext, row: Underlay) => void) => {
+  removeOnInsert = (cb: (ctx: EventContext, row: UnitTemplate) => void) => {
     return this.tableCache.removeOnInsert(cb);
   }
 
-  onDelete = (cb: (ctx: EventContext, row: Underlay) => void) => {
+  onDelete = (cb: (ctx: EventContext, row: UnitTemplate) => void) => {
     return this.tableCache.onDelete(cb);
   }
 
-  removeOnDelete = (cb: (ctx: EventContext, row: Underlay) => void) => {
+  removeOnDelete = (cb: (ctx: EventContext, row: UnitTemplate) => void) => {
     return this.tableCache.removeOnDelete(cb);
   }
 
   // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: Underlay, newRow: Underlay) => void) => {
+  onUpdate = (cb: (ctx: EventContext, oldRow: UnitTemplate, newRow: UnitTemplate) => void) => {
     return this.tableCache.onUpdate(cb);
   }
 
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: Underlay, newRow: Underlay) => void) => {
+  removeOnUpdate = (cb: (ctx: EventContext, onRow: UnitTemplate, newRow: UnitTemplate) => void) => {
     return this.tableCache.removeOnUpdate(cb);
   }}
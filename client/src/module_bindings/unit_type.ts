@@ -35,6 +35,8 @@ import type {
 } from "@clockworklabs/spacetimedb-sdk";
 import { ShapeType as __ShapeType } from "./shape_type_type";
 import { Position as __Position } from "./position_type";
+import { Visibility as __Visibility } from "./visibility_type";
+import { TextContent as __TextContent } from "./text_content_type";
 
 export type Unit = {
   id: bigint,
@@ -43,6 +45,14 @@ export type Unit = {
   size: number[],
   color: string,
   position: __Position[],
+  rotation: number,
+  zOrder: number,
+  owner: Identity | undefined,
+  visibility: __Visibility,
+  text: __TextContent | undefined,
+  factionId: bigint | undefined,
+  squadId: bigint | undefined,
+  visionRadius: number,
 };
 
 /**
@@ -61,6 +71,14 @@ export namespace Unit {
       new ProductTypeElement("size", AlgebraicType.createArrayType(AlgebraicType.createU32Type())),
       new ProductTypeElement("color", AlgebraicType.createStringType()),
       new ProductTypeElement("position", AlgebraicType.createArrayType(__Position.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("rotation", AlgebraicType.createF32Type()),
+      new ProductTypeElement("zOrder", AlgebraicType.createI32Type()),
+      new ProductTypeElement("owner", AlgebraicType.createOptionType(AlgebraicType.createIdentityType())),
+      new ProductTypeElement("visibility", __Visibility.getTypeScriptAlgebraicType()),
+      new ProductTypeElement("text", AlgebraicType.createOptionType(__TextContent.getTypeScriptAlgebraicType())),
+      new ProductTypeElement("factionId", AlgebraicType.createOptionType(AlgebraicType.createU64Type())),
+      new ProductTypeElement("squadId", AlgebraicType.createOptionType(AlgebraicType.createU64Type())),
+      new ProductTypeElement("visionRadius", AlgebraicType.createU32Type()),
     ]);
   }
 
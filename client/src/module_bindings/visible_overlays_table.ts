@@ -33,23 +33,26 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+
 import { Overlay } from "./overlay_type";
 import { ShapeType as __ShapeType } from "./shape_type_type";
 import { Position as __Position } from "./position_type";
+import { Visibility as __Visibility } from "./visibility_type";
+import { TextContent as __TextContent } from "./text_content_type";
 
 import type { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `overlay`.
+ * Table handle for the table `visible_overlays`.
  *
- * Obtain a handle from the [`overlay`] property on [`RemoteTables`],
- * like `ctx.db.overlay`.
+ * Obtain a handle from the [`visibleOverlays`] property on [`RemoteTables`],
+ * like `ctx.db.visibleOverlays`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.overlay.on_insert(...)`.
+ * like `ctx.db.visibleOverlays.on_insert(...)`.
  */
-export class OverlayTableHandle {
+export class VisibleOverlaysTableHandle {
   tableCache: TableCache<Overlay>;
 
   constructor(tableCache: TableCache<Overlay>) {
@@ -63,28 +66,6 @@ export class OverlayTableHandle {
   iter(): Iterable<Overlay> {
     return this.tableCache.iter();
   }
-  /**
-   * Access to the `id` unique index on the table `overlay`,
-   * which allows point queries on the field of the same name
-   * via the [`OverlayIdUnique.find`] method.
-   *
-   * Users are encouraged not to explicitly reference this type,
-   * but to directly chain method calls,
-   * like `ctx.db.overlay.id().find(...)`.
-   *
-   * Get a handle on the `id` unique index on the table `overlay`.
-   */
-  id = {
-    // Find the subscribed row whose `id` column value is equal to `col_val`,
-    // if such a row is present in the client cache.
-    find: (col_val: bigint): Overlay | undefined => {
-      for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.id, col_val)) {
-          return row;
-        }
-      }
-    },
-  };
 
   onInsert = (cb: (ctx: EventContext, row: Overlay) => void) => {
     return this.tableCache.onInsert(cb);
@@ -100,13 +81,4 @@ export class OverlayTableHandle {
 
   removeOnDelete = (cb: (ctx: EventContext, row: Overlay) => void) => {
     return this.tableCache.removeOnDelete(cb);
-  }
-
-  // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: Overlay, newRow: Overlay) => void) => {
-    return this.tableCache.onUpdate(cb);
-  }
-
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: Overlay, newRow: Overlay) => void) => {
-    return this.tableCache.removeOnUpdate(cb);
   }}
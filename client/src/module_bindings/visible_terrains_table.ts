@@ -33,23 +33,26 @@ import type {
   ReducerEventContextInterface,
   SubscriptionEventContextInterface,
 } from "@clockworklabs/spacetimedb-sdk";
+
 import { Terrain } from "./terrain_type";
 import { ShapeType as __ShapeType } from "./shape_type_type";
 import { Position as __Position } from "./position_type";
+import { Visibility as __Visibility } from "./visibility_type";
+import { TerrainKind as __TerrainKind } from "./terrain_kind_type";
 
 import type { EventContext, Reducer, RemoteReducers, RemoteTables } from ".";
 
 /**
- * Table handle for the table `terrain`.
+ * Table handle for the table `visible_terrains`.
  *
- * Obtain a handle from the [`terrain`] property on [`RemoteTables`],
- * like `ctx.db.terrain`.
+ * Obtain a handle from the [`visibleTerrains`] property on [`RemoteTables`],
+ * like `ctx.db.visibleTerrains`.
  *
  * Users are encouraged not to explicitly reference this type,
  * but to directly chain method calls,
- * like `ctx.db.terrain.on_insert(...)`.
+ * like `ctx.db.visibleTerrains.on_insert(...)`.
  */
-export class TerrainTableHandle {
+export class VisibleTerrainsTableHandle {
   tableCache: TableCache<Terrain>;
 
   constructor(tableCache: TableCache<Terrain>) {
@@ -63,28 +66,6 @@ export class TerrainTableHandle {
   iter(): Iterable<Terrain> {
     return this.tableCache.iter();
   }
-  /**
-   * Access to the `id` unique index on the table `terrain`,
-   * which allows point queries on the field of the same name
-   * via the [`TerrainIdUnique.find`] method.
-   *
-   * Users are encouraged not to explicitly reference this type,
-   * but to directly chain method calls,
-   * like `ctx.db.terrain.id().find(...)`.
-   *
-   * Get a handle on the `id` unique index on the table `terrain`.
-   */
-  id = {
-    // Find the subscribed row whose `id` column value is equal to `col_val`,
-    // if such a row is present in the client cache.
-    find: (col_val: bigint): Terrain | undefined => {
-      for (let row of this.tableCache.iter()) {
-        if (deepEqual(row.id, col_val)) {
-          return row;
-        }
-      }
-    },
-  };
 
   onInsert = (cb: (ctx: EventContext, row: Terrain) => void) => {
     return this.tableCache.onInsert(cb);
@@ -100,13 +81,4 @@ export class TerrainTableHandle {
 
   removeOnDelete = (cb: (ctx: EventContext, row: Terrain) => void) => {
     return this.tableCache.removeOnDelete(cb);
-  }
-
-  // Updates are only defined for tables with primary keys.
-  onUpdate = (cb: (ctx: EventContext, oldRow: Terrain, newRow: Terrain) => void) => {
-    return this.tableCache.onUpdate(cb);
-  }
-
-  removeOnUpdate = (cb: (ctx: EventContext, onRow: Terrain, newRow: Terrain) => void) => {
-    return this.tableCache.removeOnUpdate(cb);
   }}
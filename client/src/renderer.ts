@@ -1,6 +1,9 @@
 import type { GameState, Unit, Terrain, Underlay, Overlay } from "./module_bindings";
 import { ShapeType } from "./module_bindings";
 
+// Distance between wrapped lines as a multiple of the font size, as the server assumes
+const TEXT_LINE_HEIGHT = 1.2;
+
 export class Renderer {
     private contexts: {
         underlay: CanvasRenderingContext2D;
@@ -33,7 +36,9 @@ export class Renderer {
     }
 
     drawShapes(ctx: CanvasRenderingContext2D, shapes: Unit[] | Terrain[] | Underlay[] | Overlay[]) {
-        for (const shape of shapes) {
+        // Same order the server picks in: by z-order, then oldest first
+        const ordered = [...shapes].sort((a, b) => a.zOrder - b.zOrder || (a.id < b.id ? -1 : a.id > b.id ? 1 : 0));
+        for (const shape of ordered) {
 
             ctx.fillStyle = shape.color;
             ctx.strokeStyle = shape.color;
@@ -45,6 +50,15 @@ export class Renderer {
                 ctx.lineWidth = 3;
             }
 
+            // Turn the piece about the same point the server does
+            ctx.save();
+            if (shape.rotation) {
+                const center = this.shapeCenter(shape);
+                ctx.translate(center.x, center.y);
+                ctx.rotate(shape.rotation * Math.PI / 180);
+                ctx.translate(-center.x, -center.y);
+            }
+
             switch (shape.shapeType.tag) {
                 case "Circle":
                     this.drawCircle(ctx, shape);
@@ -62,7 +76,26 @@ export class Renderer {
                     this.drawText(ctx, shape);
                     break;
             }
+            ctx.restore();
+
+            // Names stay upright whichever way the piece is turned
+            if (shape.shapeType.tag !== "Text") {
+                this.drawLabel(ctx, shape);
+            }
+        }
+    }
+
+    // The reference position for circles, rectangles and text, the vertex average for lines and polygons
+    private shapeCenter(shape: Unit | Terrain | Underlay | Overlay): { x: number, y: number } {
+        const tag = shape.shapeType.tag;
+        if ((tag === "Line" || tag === "Polygon") && shape.position.length > 0) {
+            const count = shape.position.length;
+            return shape.position.reduce(
+                (acc, p) => ({ x: acc.x + p.x / count, y: acc.y + p.y / count }),
+                { x: 0, y: 0 }
+            );
         }
+        return shape.position[0] ?? { x: 0, y: 0 };
     }
 
     private drawCircle(ctx: CanvasRenderingContext2D, shape: Unit | Terrain | Underlay | Overlay) {
@@ -111,9 +144,52 @@ export class Renderer {
     }
 
     private drawText(ctx: CanvasRenderingContext2D, shape: Unit | Terrain | Underlay | Overlay) {
-        if (!shape.position?.[0] || !shape.size?.[0]) return;
+        const text = 'text' in shape ? shape.text : undefined;
+        if (!shape.position?.[0] || !text) return;
         const pos = shape.position[0];
-        ctx.font = `${shape.size[0]}px Arial`;
-        ctx.fillText(shape.color, pos.x, pos.y);
+        ctx.font = `${text.fontSize}px Arial`;
+        ctx.textAlign = text.align.tag === "Center" ? "center" : text.align.tag === "Right" ? "right" : "left";
+        ctx.textBaseline = "alphabetic";
+        const lines = text.maxWidth ? this.wrapText(ctx, text.content, text.maxWidth) : [text.content];
+        lines.forEach((line, i) => ctx.fillText(line, pos.x, pos.y + i * text.fontSize * TEXT_LINE_HEIGHT));
+    }
+
+    // Writes a circle, rectangle, line or polygon's text across its center
+    private drawLabel(ctx: CanvasRenderingContext2D, shape: Unit | Terrain | Underlay | Overlay) {
+        const text = 'text' in shape ? shape.text : undefined;
+        if (!shape.position?.[0] || !text) return;
+        const center = this.shapeCenter(shape);
+        const lineHeight = text.fontSize * TEXT_LINE_HEIGHT;
+        ctx.fillStyle = '#000000';
+        ctx.font = `${text.fontSize}px Arial`;
+        ctx.textAlign = "center";
+        ctx.textBaseline = "middle";
+        const lines = text.maxWidth ? this.wrapText(ctx, text.content, text.maxWidth) : [text.content];
+        const top = center.y - (lines.length - 1) * lineHeight / 2;
+        lines.forEach((line, i) => ctx.fillText(line, center.x, top + i * lineHeight));
+    }
+
+    // Breaks text into lines no wider than maxWidth, splitting long words if needed
+    private wrapText(ctx: CanvasRenderingContext2D, content: string, maxWidth: number): string[] {
+        const lines: string[] = [];
+        let line = "";
+        for (const word of content.split(/\s+/)) {
+            const candidate = line ? `${line} ${word}` : word;
+            if (ctx.measureText(candidate).width <= maxWidth) {
+                line = candidate;
+                continue;
+            }
+            if (line) lines.push(line);
+            line = "";
+            for (const char of word) {
+                if (line && ctx.measureText(line + char).width > maxWidth) {
+                    lines.push(line);
+                    line = "";
+                }
+                line += char;
+            }
+        }
+        if (line) lines.push(line);
+        return lines;
     }
 }
@@ -215,7 +215,9 @@ export class GameSetupTab {
                     shapeTypeEnum[shapeType],
                     size,
                     colorName,
-                    position
+                    position,
+                    undefined,
+                    undefined
                 );
             } else if (whatToAdd === 'terrain') {
                 const traversable = !!form.querySelector('input[name=traversable]') && (form.querySelector('input[name=traversable]') as HTMLInputElement).checked;
@@ -225,7 +227,8 @@ export class GameSetupTab {
                     size,
                     colorName,
                     position,
-                    traversable
+                    traversable,
+                    undefined
                 );
             } else if (whatToAdd === 'underlay') {
                 this.dbConnection.reducers.addUnderlay(
@@ -233,7 +236,8 @@ export class GameSetupTab {
                     shapeTypeEnum[shapeType],
                     size,
                     colorName,
-                    position
+                    position,
+                    undefined
                 );
             } else if (whatToAdd === 'overlay') {
                 this.dbConnection.reducers.addOverlay(
@@ -241,7 +245,9 @@ export class GameSetupTab {
                     shapeTypeEnum[shapeType],
                     size,
                     colorName,
-                    position
+                    position,
+                    undefined,
+                    undefined
                 );
             }
         });
@@ -363,7 +369,7 @@ export class GameSetupTab {
         }
         
         // Send coordinates to server for deletion
-        this.dbConnection.reducers.deleteAtCoordinates(this.game_id, x, y);
+        this.dbConnection.reducers.deleteAtCoordinates(this.game_id, x, y, []);
         
         // If in delete one mode, turn it off after one deletion
         if (this.deleteOneMode) {
@@ -0,0 +1,67 @@
+//! Compares one drag step against a full rebuild of the collision world with
+//! the same step against only the pieces found through the broad-phase grid.
+//! Run with `cargo bench -p tabletap-geometry`.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::Instant;
+
+use tabletap_geometry::{cells_for_aabb, piece_aabb, swept_aabb, sweep_piece, Collidable, CollisionWorld, Position, ShapeType};
+
+#[derive(Clone)]
+struct Piece {
+    id: u64,
+    position: Vec<Position>,
+    size: Vec<u32>,
+}
+
+impl Collidable for Piece {
+    fn id(&self) -> u64 { self.id }
+    fn shape_type(&self) -> &ShapeType { &ShapeType::Circle }
+    fn position(&self) -> &[Position] { &self.position }
+    fn size(&self) -> &[u32] { &self.size }
+    fn rotation(&self) -> f32 { 0.0 }
+    fn traversable(&self) -> bool { false }
+}
+
+fn main() {
+    let pieces: Vec<Piece> = (0..3000u64)
+        .map(|i| Piece {
+            id: i + 1,
+            position: vec![Position { x: 5 + (i % 60) as u32 * 10, y: 5 + (i / 60) as u32 * 8 }],
+            size: vec![8],
+        })
+        .collect();
+    let mut grid: HashMap<(i32, i32), Vec<usize>> = HashMap::new();
+    for (index, piece) in pieces.iter().enumerate() {
+        for cell in cells_for_aabb(&piece_aabb(piece).unwrap()) {
+            grid.entry(cell).or_default().push(index);
+        }
+    }
+    let moving = &pieces[1500];
+    let steps = 200;
+
+    let start = Instant::now();
+    for _ in 0..steps {
+        let world = CollisionWorld::new(&pieces, true, Some(moving.id));
+        black_box(sweep_piece(moving, 3, 0, &[&world]));
+    }
+    let full = start.elapsed() / steps;
+
+    let start = Instant::now();
+    for _ in 0..steps {
+        let mut indices: Vec<usize> = cells_for_aabb(&swept_aabb(moving, 3, 0))
+            .filter_map(|cell| grid.get(&cell))
+            .flatten()
+            .copied()
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        let nearby: Vec<Piece> = indices.into_iter().map(|i| pieces[i].clone()).collect();
+        let world = CollisionWorld::new(&nearby, true, Some(moving.id));
+        black_box(sweep_piece(moving, 3, 0, &[&world]));
+    }
+    let indexed = start.elapsed() / steps;
+
+    println!("{} pieces: full rebuild {:?} per step, grid lookup {:?} per step", pieces.len(), full, indexed);
+}
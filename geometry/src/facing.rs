@@ -0,0 +1,34 @@
+use rapier2d::prelude::*;
+
+use crate::shape::{shape_center, Collidable};
+
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FacingArc {
+    Front,
+    Flank,
+    Rear,
+}
+
+/// Classifies a point by the arc of a piece it falls in. The front arc is
+/// `front` degrees wide and centered on the piece's facing (its rotation),
+/// the rear arc `rear` degrees wide around the opposite direction, and
+/// everything in between is flank. Points on a boundary count as front or
+/// rear.
+pub fn facing_arc(item: &dyn Collidable, front: f32, rear: f32, point: Point<Real>) -> FacingArc {
+    let center = shape_center(item.shape_type(), item.position());
+    let offset = point - center;
+    if offset.norm() == 0.0 {
+        return FacingArc::Front;
+    }
+    let bearing = offset.y.atan2(offset.x).to_degrees();
+    // Angle off the facing direction, from 0 (straight ahead) to 180 (straight behind)
+    let off_facing = (bearing - item.rotation() + 180.0).rem_euclid(360.0) - 180.0;
+    if off_facing.abs() <= front / 2.0 {
+        FacingArc::Front
+    } else if off_facing.abs() >= 180.0 - rear / 2.0 {
+        FacingArc::Rear
+    } else {
+        FacingArc::Flank
+    }
+}
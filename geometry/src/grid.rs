@@ -0,0 +1,39 @@
+use rapier2d::parry::bounding_volume::{Aabb, BoundingVolume};
+use rapier2d::prelude::*;
+
+use crate::shape::{pick_shape, Collidable};
+
+/// Side length, in board units, of the broad-phase grid cells.
+pub const SPATIAL_CELL_SIZE: f32 = 50.0;
+
+/// The grid cells an axis-aligned bounding box touches.
+pub fn cells_for_aabb(aabb: &Aabb) -> impl Iterator<Item = (i32, i32)> {
+    let min_x = (aabb.mins.x / SPATIAL_CELL_SIZE).floor() as i32;
+    let min_y = (aabb.mins.y / SPATIAL_CELL_SIZE).floor() as i32;
+    let max_x = (aabb.maxs.x / SPATIAL_CELL_SIZE).floor() as i32;
+    let max_y = (aabb.maxs.y / SPATIAL_CELL_SIZE).floor() as i32;
+    (min_x..=max_x).flat_map(move |x| (min_y..=max_y).map(move |y| (x, y)))
+}
+
+/// The bounding box of everything that can hit or be picked on a piece.
+pub fn piece_aabb(item: &dyn Collidable) -> Option<Aabb> {
+    let (shape, iso) = pick_shape(item)?;
+    Some(shape.compute_aabb(&iso))
+}
+
+pub fn point_aabb(x: u32, y: u32) -> Aabb {
+    let point = Point::new(x as f32, y as f32);
+    Aabb::new(point, point)
+}
+
+/// The area a piece covers while moving in a straight line by (dx, dy),
+/// for looking up what it could hit. Pieces with no shape at all only cover
+/// their reference point.
+pub fn swept_aabb(item: &dyn Collidable, dx: i32, dy: i32) -> Aabb {
+    let Some(aabb) = piece_aabb(item) else {
+        let start = item.position().first().map_or((0, 0), |p| (p.x, p.y));
+        return point_aabb(start.0, start.1);
+    };
+    let shift = vector![dx as f32, dy as f32];
+    aabb.merged(&Aabb::new(aabb.mins + shift, aabb.maxs + shift))
+}
@@ -0,0 +1,29 @@
+//! Board geometry for TableTap: how pieces are shaped, what they collide
+//! with, what they can see and how they move. Nothing here depends on
+//! SpacetimeDB, so the rules can be tested with a plain `cargo test`.
+
+mod facing;
+mod grid;
+mod path;
+mod shape;
+mod sight;
+mod template;
+mod text;
+mod world;
+
+pub use facing::{facing_arc, FacingArc};
+pub use grid::{cells_for_aabb, piece_aabb, point_aabb, swept_aabb, SPATIAL_CELL_SIZE};
+pub use path::{find_path, PATH_GRID_STEP};
+pub use shape::{
+    create_shape_obj, outline_points, pick_shape, polygon_is_convex, rectangle_from_corners, shape_center, shape_isometry,
+    translate_positions, triangulate_polygon, validate_geometry, validate_polygon, Collidable, Position, ShapeType,
+    MAX_POLYGON_POINTS,
+};
+pub use sight::{line_of_sight, measure_items, sight_points, LineOfSight};
+pub use template::{create_template_shape, template_coverage, Template, TemplateKind};
+pub use text::{
+    text_bounds, validate_text, TextAlign, TextContent, MAX_TEXT_LENGTH, TEXT_ASCENT, TEXT_CHAR_WIDTH, TEXT_LINE_HEIGHT,
+};
+pub use world::{find_item_at_point, shape_overlaps, sweep_piece, CollisionWorld};
+
+pub use rapier2d;
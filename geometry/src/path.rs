@@ -0,0 +1,107 @@
+use std::collections::{BinaryHeap, HashMap};
+
+use rapier2d::prelude::*;
+
+use crate::shape::{create_shape_obj, shape_isometry, Collidable, Position};
+use crate::world::CollisionWorld;
+
+/// Grid spacing, in board units, of the nodes the path planner searches over.
+pub const PATH_GRID_STEP: i32 = 10;
+
+#[derive(PartialEq)]
+struct PathNode {
+    estimate: f32,
+    cell: (i32, i32),
+}
+
+impl Eq for PathNode {}
+
+impl Ord for PathNode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        // Reversed so the BinaryHeap pops the lowest estimate first
+        other.estimate.total_cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for PathNode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Finds a collision-free route for a piece's shape to `target` with an A*
+/// search over a grid anchored at the piece's reference point, then shortens
+/// it by skipping every waypoint that can be reached directly. Waypoints stay
+/// within a board of `bounds` (width, height), are reference positions and
+/// end at `target`.
+pub fn find_path(item: &dyn Collidable, target: &Position, worlds: &[&CollisionWorld], bounds: (u32, u32)) -> Option<Vec<Position>> {
+    let start = item.position().first()?;
+    let shape = create_shape_obj(item.shape_type(), item.position(), item.size())?;
+    let base_iso = shape_isometry(item.shape_type(), item.position(), item.rotation());
+    let (start_x, start_y) = (start.x as i32, start.y as i32);
+    let (target_x, target_y) = (target.x as i32, target.y as i32);
+
+    let point_of = |cell: (i32, i32)| (start_x + cell.0 * PATH_GRID_STEP, start_y + cell.1 * PATH_GRID_STEP);
+    let clear = |from: (i32, i32), to: (i32, i32)| {
+        let iso = Isometry::translation((from.0 - start_x) as f32, (from.1 - start_y) as f32) * base_iso;
+        let movement = vector![(to.0 - from.0) as f32, (to.1 - from.1) as f32];
+        worlds.iter().all(|world| world.cast_shape(&*shape, &iso, movement).is_none())
+    };
+    let in_bounds = |(x, y): (i32, i32)| x >= 0 && y >= 0 && x <= bounds.0 as i32 && y <= bounds.1 as i32;
+    let distance = |a: (i32, i32), b: (i32, i32)| (((a.0 - b.0).pow(2) + (a.1 - b.1).pow(2)) as f32).sqrt();
+
+    let goal = (target_x, target_y);
+    let mut open = BinaryHeap::new();
+    let mut cost: HashMap<(i32, i32), f32> = HashMap::new();
+    let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+    cost.insert((0, 0), 0.0);
+    open.push(PathNode { estimate: distance(point_of((0, 0)), goal), cell: (0, 0) });
+
+    let mut last_cell = None;
+    while let Some(PathNode { cell, .. }) = open.pop() {
+        let point = point_of(cell);
+        if distance(point, goal) <= PATH_GRID_STEP as f32 * 1.5 && clear(point, goal) {
+            last_cell = Some(cell);
+            break;
+        }
+        let cell_cost = cost[&cell];
+        for (ox, oy) in [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)] {
+            let next = (cell.0 + ox, cell.1 + oy);
+            let next_point = point_of(next);
+            if !in_bounds(next_point) {
+                continue;
+            }
+            let next_cost = cell_cost + distance(point, next_point);
+            if cost.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+            if !clear(point, next_point) {
+                continue;
+            }
+            cost.insert(next, next_cost);
+            came_from.insert(next, cell);
+            open.push(PathNode { estimate: next_cost + distance(next_point, goal), cell: next });
+        }
+    }
+
+    let mut cell = last_cell?;
+    let mut route = vec![goal, point_of(cell)];
+    while let Some(&previous) = came_from.get(&cell) {
+        route.push(point_of(previous));
+        cell = previous;
+    }
+    route.reverse();
+
+    // String pulling: from each waypoint jump to the furthest one in sight
+    let mut waypoints = Vec::new();
+    let mut current = 0;
+    while current < route.len() - 1 {
+        let mut next = route.len() - 1;
+        while next > current + 1 && !clear(route[current], route[next]) {
+            next -= 1;
+        }
+        waypoints.push(Position { x: route[next].0 as u32, y: route[next].1 as u32 });
+        current = next;
+    }
+    Some(waypoints)
+}
@@ -0,0 +1,337 @@
+use rapier2d::prelude::*;
+
+use crate::text::{text_bounds, TextContent, TEXT_ASCENT, TEXT_CHAR_WIDTH};
+
+/// Most vertices a polygon may have. Validating and triangulating an outline
+/// take quadratic and cubic time in its vertex count.
+pub const MAX_POLYGON_POINTS: usize = 64;
+
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Position {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ShapeType {
+    Circle,
+    Rectangle,
+    Line,
+    Polygon,
+    Text,
+}
+
+/// Anything with a shape on the board. Positions and sizes follow the forms
+/// described on [`validate_geometry`].
+pub trait Collidable {
+    fn id(&self) -> u64;
+    fn shape_type(&self) -> &ShapeType;
+    fn position(&self) -> &[Position];
+    fn size(&self) -> &[u32];
+    /// Clockwise rotation in degrees about the shape center
+    fn rotation(&self) -> f32;
+    fn traversable(&self) -> bool;
+    /// The label a piece carries, which for Text shapes sizes its hit box
+    fn text(&self) -> Option<&TextContent> {
+        None
+    }
+}
+
+pub fn create_shape_obj(shape_type: &ShapeType, positions: &[Position], sizes: &[u32]) -> Option<SharedShape> {
+    if positions.is_empty() {
+        return None;
+    }
+
+    match shape_type {
+        ShapeType::Circle => {
+            if sizes.is_empty() {
+                return None;
+            }
+            let radius = sizes[0] as f32 / 2.0;
+            Some(SharedShape::ball(radius))
+        },
+        ShapeType::Rectangle => {
+            if sizes.len() < 2 {
+                return None;
+            }
+            Some(SharedShape::cuboid(sizes[0] as f32 / 2.0, sizes[1] as f32 / 2.0))
+        },
+        ShapeType::Polygon => {
+            if positions.len() < 3 {
+                return None; // Need at least 3 points for a polygon
+            }
+            
+            let mut vertices = Vec::with_capacity(positions.len());
+            for pos in positions {
+                vertices.push(Point::new(pos.x as f32, pos.y as f32));
+            }
+            
+            if polygon_is_convex(&vertices) {
+                return SharedShape::convex_polyline(vertices);
+            }
+
+            // Concave outlines become a compound of triangles
+            let parts = triangulate_polygon(&vertices)?
+                .into_iter()
+                .map(|[a, b, c]| (Isometry::identity(), SharedShape::triangle(a, b, c)))
+                .collect();
+            Some(SharedShape::compound(parts))
+        },
+        ShapeType::Line => {
+            if positions.len() < 2 {
+                return None;
+            }
+            let p1 = Point::new(positions[0].x as f32, positions[0].y as f32);
+            let p2 = Point::new(positions[1].x as f32, positions[1].y as f32);
+            // Thick lines are rounded at the ends, like the stroke drawn for them
+            match sizes.first() {
+                Some(&thickness) if thickness > 0 => Some(SharedShape::capsule(p1, p2, thickness as f32 / 2.0)),
+                _ => Some(SharedShape::segment(p1, p2)),
+            }
+        },
+        _ => None, // Text shapes don't have collisions
+    }
+}
+
+/// The shape a click has to land in to pick the item, in board coordinates.
+/// This is the collision shape, except that Text, which never collides, gets
+/// an approximate box around its string. Text shapes without content are
+/// sized as a single character of `size[0]`.
+pub fn pick_shape(item: &dyn Collidable) -> Option<(SharedShape, Isometry<Real>)> {
+    let (shape_type, positions, sizes) = (item.shape_type(), item.position(), item.size());
+    if let ShapeType::Text = shape_type {
+        let anchor = positions.first()?;
+        let (left, top, right, bottom) = match item.text() {
+            Some(text) => text_bounds(text),
+            None => {
+                let font_size = *sizes.first()? as f32;
+                (0.0, -font_size * TEXT_ASCENT, font_size * TEXT_CHAR_WIDTH, font_size * (1.0 - TEXT_ASCENT))
+            }
+        };
+        let anchor = Point::new(anchor.x as f32, anchor.y as f32);
+        let iso = Isometry::rotation_wrt_point(Rotation::new(item.rotation().to_radians()), anchor)
+            * Isometry::translation(anchor.x + (left + right) / 2.0, anchor.y + (top + bottom) / 2.0);
+        return Some((SharedShape::cuboid((right - left) / 2.0, (bottom - top) / 2.0), iso));
+    }
+    let shape = create_shape_obj(shape_type, positions, sizes)?;
+    Some((shape, shape_isometry(shape_type, positions, item.rotation())))
+}
+
+/// Checks that positions and sizes follow the one representation every shape
+/// is stored, drawn and collided with:
+///
+/// - Circle: `[center]`, size `[diameter]`
+/// - Rectangle: `[center]`, size `[width, height]`
+/// - Line: `[start, end]`, size `[thickness]`
+/// - Polygon: 3 to [`MAX_POLYGON_POINTS`] vertices of a simple outline, size
+///   `[thickness]`
+/// - Text: `[anchor]`, size `[font size]`
+pub fn validate_geometry(shape_type: &ShapeType, positions: &[Position], sizes: &[u32]) -> Result<(), String> {
+    let (point_count, size_count) = match shape_type {
+        ShapeType::Circle => (1, 1),
+        ShapeType::Rectangle => (1, 2),
+        ShapeType::Line => (2, 1),
+        ShapeType::Polygon => (positions.len(), 1),
+        ShapeType::Text => (1, 1),
+    };
+    if positions.len() != point_count {
+        return Err(format!("{:?} needs {} position(s), got {}", shape_type, point_count, positions.len()));
+    }
+    if sizes.len() != size_count {
+        return Err(format!("{:?} needs {} size value(s), got {}", shape_type, size_count, sizes.len()));
+    }
+    match shape_type {
+        ShapeType::Line if positions[0].x == positions[1].x && positions[0].y == positions[1].y => {
+            Err("Line start and end must be different points".to_string())
+        }
+        ShapeType::Polygon => validate_polygon(positions),
+        // Lines and polygons may have zero thickness; everything else must have an extent
+        ShapeType::Circle | ShapeType::Rectangle | ShapeType::Text if sizes.contains(&0) => {
+            Err(format!("{:?} sizes must be greater than zero", shape_type))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Rectangles used to be stored with their two corners as positions. Returns
+/// the center-and-size form for such a rectangle, or `None` if it already
+/// has one position.
+pub fn rectangle_from_corners(shape_type: &ShapeType, positions: &[Position]) -> Option<(Vec<Position>, Vec<u32>)> {
+    match (shape_type, positions) {
+        (ShapeType::Rectangle, [a, b]) => Some((
+            vec![Position { x: (a.x + b.x) / 2, y: (a.y + b.y) / 2 }],
+            vec![a.x.abs_diff(b.x), a.y.abs_diff(b.y)],
+        )),
+        _ => None,
+    }
+}
+
+pub fn polygon_is_convex(vertices: &[Point<Real>]) -> bool {
+
+    let n = vertices.len();
+    let mut sign = 0.0;
+    for i in 0..n {
+        let turn = (vertices[(i + 1) % n] - vertices[i]).perp(&(vertices[(i + 2) % n] - vertices[(i + 1) % n]));
+        if turn == 0.0 {
+            continue;
+        }
+        if sign == 0.0 {
+            sign = turn.signum();
+        } else if turn.signum() != sign {
+            return false;
+        }
+    }
+    true
+}
+
+/// Splits a simple polygon into triangles by ear clipping. Returns `None` if
+/// the outline is degenerate or self-intersecting.
+pub fn triangulate_polygon(vertices: &[Point<Real>]) -> Option<Vec<[Point<Real>; 3]>> {
+    let signed_area: f32 = (0..vertices.len())
+        .map(|i| vertices[i].coords.perp(&vertices[(i + 1) % vertices.len()].coords))
+        .sum();
+    let mut remaining: Vec<Point<Real>> = vertices.to_vec();
+    if signed_area < 0.0 {
+        remaining.reverse();
+    }
+
+    let turn = |a: Point<Real>, b: Point<Real>, c: Point<Real>| (b - a).perp(&(c - b));
+    let inside_or_on = |p: Point<Real>, a: Point<Real>, b: Point<Real>, c: Point<Real>| {
+        turn(a, b, p) >= 0.0 && turn(b, c, p) >= 0.0 && turn(c, a, p) >= 0.0
+    };
+
+    let mut triangles = Vec::with_capacity(vertices.len().saturating_sub(2));
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        // Collinear vertices can never be ears, so drop them first
+        if let Some(flat) = (0..n).find(|&i| turn(remaining[(i + n - 1) % n], remaining[i], remaining[(i + 1) % n]) == 0.0) {
+            remaining.remove(flat);
+            continue;
+        }
+        let ear = (0..n).find(|&i| {
+            let (a, b, c) = (remaining[(i + n - 1) % n], remaining[i], remaining[(i + 1) % n]);
+            turn(a, b, c) > 0.0
+                && !remaining
+                    .iter()
+                    .enumerate()
+                    .any(|(j, &p)| j != i && j != (i + n - 1) % n && j != (i + 1) % n && inside_or_on(p, a, b, c))
+        })?;
+        triangles.push([remaining[(ear + n - 1) % n], remaining[ear], remaining[(ear + 1) % n]]);
+        remaining.remove(ear);
+    }
+    if remaining.len() == 3 && turn(remaining[0], remaining[1], remaining[2]) > 0.0 {
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+    }
+    if triangles.is_empty() {
+        return None;
+    }
+    Some(triangles)
+}
+
+/// Checks that polygon vertices describe a simple outline: three to
+/// [`MAX_POLYGON_POINTS`] corners, no repeated points and no edges crossing or
+/// touching each other.
+pub fn validate_polygon(positions: &[Position]) -> Result<(), String> {
+    let n = positions.len();
+    if n < 3 {
+        return Err(format!("A polygon needs at least 3 points, got {}", n));
+    }
+    if n > MAX_POLYGON_POINTS {
+        return Err(format!("A polygon may have at most {} points, got {}", MAX_POLYGON_POINTS, n));
+    }
+    let point = |i: usize| (positions[i % n].x as i64, positions[i % n].y as i64);
+    let orientation = |a: (i64, i64), b: (i64, i64), c: (i64, i64)| ((b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)).signum();
+    let on_segment = |a: (i64, i64), b: (i64, i64), p: (i64, i64)| {
+        p.0 >= a.0.min(b.0) && p.0 <= a.0.max(b.0) && p.1 >= a.1.min(b.1) && p.1 <= a.1.max(b.1)
+    };
+    let segments_touch = |a: (i64, i64), b: (i64, i64), c: (i64, i64), d: (i64, i64)| {
+        let (o1, o2, o3, o4) = (orientation(a, b, c), orientation(a, b, d), orientation(c, d, a), orientation(c, d, b));
+        (o1 != o2 && o3 != o4)
+            || (o1 == 0 && on_segment(a, b, c))
+            || (o2 == 0 && on_segment(a, b, d))
+            || (o3 == 0 && on_segment(c, d, a))
+            || (o4 == 0 && on_segment(c, d, b))
+    };
+
+    for i in 0..n {
+        if point(i) == point(i + 1) {
+            return Err(format!("Polygon point {} repeats the point before it", (i + 1) % n));
+        }
+        // Neighbouring edges share a corner, so they only clash if they fold back onto each other
+        let (a, b, c) = (point(i), point(i + 1), point(i + 2));
+        if orientation(a, b, c) == 0 && (b.0 - a.0) * (c.0 - b.0) + (b.1 - a.1) * (c.1 - b.1) < 0 {
+            return Err(format!("Polygon edges {} and {} fold back onto each other", i, (i + 1) % n));
+        }
+        for j in i + 2..n {
+            if i == 0 && j == n - 1 {
+                continue;
+            }
+            if segments_touch(point(i), point(i + 1), point(j), point(j + 1)) {
+                return Err(format!("Polygon is self-intersecting: edges {} and {} cross", i, j));
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn shape_isometry(shape_type: &ShapeType, positions: &[Position], rotation: f32) -> Isometry<Real> {
+    let angle = rotation.to_radians();
+    match shape_type {
+        ShapeType::Circle | ShapeType::Rectangle => {
+            let x = positions[0].x as f32;
+            let y = positions[0].y as f32;
+            Isometry::new(vector![x, y], angle)
+        },
+        // Polygon and Line vertices are already in board coordinates, so they
+        // are turned about their center
+        _ => Isometry::rotation_wrt_point(Rotation::new(angle), shape_center(shape_type, positions)),
+    }
+}
+
+/// The point distances and sight lines are measured from: the reference
+/// position for circles and rectangles, the vertex average for everything else.
+pub fn shape_center(shape_type: &ShapeType, positions: &[Position]) -> Point<Real> {
+    match shape_type {
+        ShapeType::Polygon | ShapeType::Line if !positions.is_empty() => {
+            let count = positions.len() as f32;
+            positions
+                .iter()
+                .fold(Point::origin(), |acc, p| acc + vector![p.x as f32 / count, p.y as f32 / count])
+        }
+        _ => positions.first().map_or(Point::origin(), |p| Point::new(p.x as f32, p.y as f32)),
+    }
+}
+
+/// The corners of a rectangle or the vertices of a polygon or line, in board
+/// coordinates and turned by the shape's rotation. Circles have no corners
+/// and yield nothing.
+pub fn outline_points(shape_type: &ShapeType, positions: &[Position], sizes: &[u32], rotation: f32) -> Vec<Point<Real>> {
+    let points: Vec<Point<Real>> = match shape_type {
+        ShapeType::Rectangle if sizes.len() >= 2 && !positions.is_empty() => {
+            let half_w = sizes[0] as f32 / 2.0;
+            let half_h = sizes[1] as f32 / 2.0;
+            [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+                .iter()
+                .map(|(sx, sy)| Point::new(sx * half_w, sy * half_h))
+                .collect()
+        }
+        ShapeType::Polygon | ShapeType::Line => {
+            positions.iter().map(|p| Point::new(p.x as f32, p.y as f32)).collect()
+        }
+        _ => return Vec::new(),
+    };
+    let iso = shape_isometry(shape_type, positions, rotation);
+    points.iter().map(|p| iso * p).collect()
+}
+
+pub fn translate_positions(positions: &[Position], dx: i32, dy: i32) -> Vec<Position> {
+    positions
+        .iter()
+        .map(|p| Position {
+            x: p.x.saturating_add_signed(dx),
+            y: p.y.saturating_add_signed(dy),
+        })
+        .collect()
+}
@@ -0,0 +1,83 @@
+use rapier2d::prelude::*;
+
+use crate::shape::{create_shape_obj, outline_points, shape_center, shape_isometry, Collidable, Position, ShapeType};
+use crate::world::CollisionWorld;
+
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LineOfSight {
+    Clear,
+    Obscured,
+    Blocked,
+}
+
+/// Points a sight line may be drawn from or to for a shape. Wide shapes use
+/// their corners (or, for circles, the edges facing across the sight line) as
+/// well as the center, pulled in slightly so rays don't start on an edge.
+pub fn sight_points(shape_type: &ShapeType, positions: &[Position], sizes: &[u32], rotation: f32, toward: Point<Real>) -> Vec<Point<Real>> {
+    if positions.is_empty() {
+        return Vec::new();
+    }
+    let center = shape_center(shape_type, positions);
+    if let ShapeType::Circle = shape_type {
+        let radius = sizes.first().copied().unwrap_or(0) as f32 / 2.0;
+        let direction = toward - center;
+        if radius <= 1.0 || direction.norm() == 0.0 {
+            return vec![center];
+        }
+        let side = vector![-direction.y, direction.x].normalize() * (radius - 1.0);
+        return vec![center, center + side, center - side];
+    }
+
+    let mut points = vec![center];
+    for corner in outline_points(shape_type, positions, sizes, rotation) {
+        let offset = center - corner;
+        points.push(if offset.norm() > 1.0 { corner + offset.normalize() } else { center });
+    }
+    points
+}
+
+/// Checks every pair of sight points between two pieces and returns the best
+/// line found: clear if any line touches nothing, obscured if the best line
+/// only passes through traversable terrain, otherwise blocked.
+pub fn line_of_sight(
+    from: &dyn Collidable,
+    to: &dyn Collidable,
+    unit_world: &CollisionWorld,
+    blocking_terrain_world: &CollisionWorld,
+    terrain_world: &CollisionWorld,
+) -> LineOfSight {
+    let to_center = shape_center(to.shape_type(), to.position());
+    let from_center = shape_center(from.shape_type(), from.position());
+    let from_points = sight_points(from.shape_type(), from.position(), from.size(), from.rotation(), to_center);
+    let to_points = sight_points(to.shape_type(), to.position(), to.size(), to.rotation(), from_center);
+
+    let mut best = LineOfSight::Blocked;
+    for &a in &from_points {
+        for &b in &to_points {
+            if unit_world.segment_hits(a, b) || blocking_terrain_world.segment_hits(a, b) {
+                continue;
+            }
+            if !terrain_world.segment_hits(a, b) {
+                return LineOfSight::Clear;
+            }
+            best = LineOfSight::Obscured;
+        }
+    }
+    best
+}
+
+/// Closest edge-to-edge distance and center-to-center distance between two
+/// items, or `None` if either has no collision shape.
+pub fn measure_items(a: &dyn Collidable, b: &dyn Collidable) -> Option<(f32, f32)> {
+    let shape_a = create_shape_obj(a.shape_type(), a.position(), a.size())?;
+    let shape_b = create_shape_obj(b.shape_type(), b.position(), b.size())?;
+    let iso_a = shape_isometry(a.shape_type(), a.position(), a.rotation());
+    let iso_b = shape_isometry(b.shape_type(), b.position(), b.rotation());
+    let edge = rapier2d::parry::query::distance(&iso_a, &*shape_a, &iso_b, &*shape_b).ok()?;
+    let center = rapier2d::na::distance(
+        &shape_center(a.shape_type(), a.position()),
+        &shape_center(b.shape_type(), b.position()),
+    );
+    Some((edge, center))
+}
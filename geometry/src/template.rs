@@ -0,0 +1,114 @@
+use rapier2d::prelude::*;
+
+use crate::shape::{create_shape_obj, outline_points, shape_center, shape_isometry, Collidable, Position, ShapeType};
+
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TemplateKind {
+    Blast,
+    Cone,
+    Line,
+    Square,
+}
+
+/// An area-of-effect template on the board. `size` is the diameter of a
+/// blast, the side of a square and the length of a cone or line; `width` is
+/// the far end of a cone or the width of a line. `direction` is in degrees,
+/// clockwise from the positive x axis.
+pub trait Template {
+    fn kind(&self) -> TemplateKind;
+    fn origin(&self) -> &Position;
+    fn size(&self) -> u32;
+    fn width(&self) -> u32;
+    fn direction(&self) -> f32;
+}
+
+/// The corners of a cone, line or square template in board coordinates.
+/// Blasts are round and have none.
+fn template_vertices(template: &dyn Template) -> Vec<Point<Real>> {
+    let origin = Point::new(template.origin().x as f32, template.origin().y as f32);
+    let size = template.size() as f32;
+    let angle = template.direction().to_radians();
+    let forward = vector![angle.cos(), angle.sin()];
+    let side = vector![-forward.y, forward.x];
+    let half_width = template.width() as f32 / 2.0;
+    match template.kind() {
+        TemplateKind::Blast => Vec::new(),
+        TemplateKind::Cone => vec![
+            origin,
+            origin + forward * size - side * half_width,
+            origin + forward * size + side * half_width,
+        ],
+        TemplateKind::Line => vec![
+            origin - side * half_width,
+            origin + forward * size - side * half_width,
+            origin + forward * size + side * half_width,
+            origin + side * half_width,
+        ],
+        TemplateKind::Square => {
+            let half = size / 2.0;
+            vec![
+                origin - forward * half - side * half,
+                origin + forward * half - side * half,
+                origin + forward * half + side * half,
+                origin - forward * half + side * half,
+            ]
+        }
+    }
+}
+
+/// Builds the collision shape of a template in board coordinates.
+pub fn create_template_shape(template: &dyn Template) -> Option<(SharedShape, Isometry<Real>)> {
+    if template.size() == 0 {
+        return None;
+    }
+    if let TemplateKind::Blast = template.kind() {
+        let radius = template.size() as f32 / 2.0;
+        return Some((SharedShape::ball(radius), Isometry::translation(template.origin().x as f32, template.origin().y as f32)));
+    }
+    SharedShape::convex_polyline(template_vertices(template)).map(|shape| (shape, Isometry::identity()))
+}
+
+/// Returns true if a circle lies completely inside a convex polygon, i.e. its
+/// center is at least `radius` inside every edge.
+fn circle_inside_convex(vertices: &[Point<Real>], center: Point<Real>, radius: f32) -> bool {
+    if vertices.len() < 3 {
+        return false;
+    }
+    let orientation = (vertices[1] - vertices[0]).perp(&(vertices[2] - vertices[0])).signum();
+    (0..vertices.len()).all(|i| {
+        let a = vertices[i];
+        let edge = vertices[(i + 1) % vertices.len()] - a;
+        edge.perp(&(center - a)) * orientation / edge.norm() >= radius
+    })
+}
+
+/// Returns whether the template touches the piece, and if so whether it
+/// covers the piece completely. Templates are convex, so a piece is covered
+/// when all of its corners are, or for circles when the center is at least a
+/// radius inside.
+pub fn template_coverage(template: &dyn Template, item: &dyn Collidable) -> Option<bool> {
+    let (template_shape, template_iso) = create_template_shape(template)?;
+    let shape = create_shape_obj(item.shape_type(), item.position(), item.size())?;
+    let iso = shape_isometry(item.shape_type(), item.position(), item.rotation());
+    if !rapier2d::parry::query::intersection_test(&template_iso, &*template_shape, &iso, &*shape).ok()? {
+        return None;
+    }
+    let covered = match (template.kind(), item.shape_type()) {
+        (TemplateKind::Blast, ShapeType::Circle) => {
+            let origin = Point::new(template.origin().x as f32, template.origin().y as f32);
+            let center = shape_center(item.shape_type(), item.position());
+            let radius = item.size().first().copied().unwrap_or(0) as f32 / 2.0;
+            (center - origin).norm() + radius <= template.size() as f32 / 2.0
+        }
+        (_, ShapeType::Circle) => {
+            let center = shape_center(item.shape_type(), item.position());
+            let radius = item.size().first().copied().unwrap_or(0) as f32 / 2.0;
+            circle_inside_convex(&template_vertices(template), center, radius)
+        }
+        _ => outline_points(item.shape_type(), item.position(), item.size(), item.rotation())
+            .iter()
+            .all(|corner| template_shape.contains_point(&template_iso, corner)),
+    };
+    Some(covered)
+}
@@ -0,0 +1,66 @@
+/// Longest text a piece may carry, in characters.
+pub const MAX_TEXT_LENGTH: usize = 200;
+/// Average glyph width as a fraction of the font size, for text hit boxes.
+pub const TEXT_CHAR_WIDTH: f32 = 0.6;
+/// Share of the font size drawn above the baseline the text sits on.
+pub const TEXT_ASCENT: f32 = 0.8;
+/// Distance between wrapped lines as a multiple of the font size.
+pub const TEXT_LINE_HEIGHT: f32 = 1.2;
+
+/// Which side of the text its anchor sits on.
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TextAlign {
+    Left,
+    Center,
+    Right,
+}
+
+/// A label drawn on the board. Text shapes show it at their anchor, which
+/// sits on the baseline of the first line; other shapes carry it as a name.
+#[cfg_attr(feature = "spacetimedb", derive(spacetimedb::SpacetimeType))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextContent {
+    pub content: String,
+    pub font_size: u32,
+    pub align: TextAlign,
+    /// Width at which the text wraps onto another line
+    pub max_width: Option<u32>,
+}
+
+pub fn validate_text(text: &TextContent, max_font_size: u32) -> Result<(), String> {
+    let length = text.content.chars().count();
+    if text.content.trim().is_empty() {
+        return Err("Text must not be empty".to_string());
+    }
+    if length > MAX_TEXT_LENGTH {
+        return Err(format!("Text is {} characters long, the maximum is {}", length, MAX_TEXT_LENGTH));
+    }
+    if text.font_size == 0 || text.font_size > max_font_size {
+        return Err(format!("Font size must be between 1 and {}, got {}", max_font_size, text.font_size));
+    }
+    if text.max_width == Some(0) {
+        return Err("Maximum text width must be greater than zero".to_string());
+    }
+    Ok(())
+}
+
+/// Rough extent of drawn text relative to its anchor, as (left, top, right,
+/// bottom) offsets with y pointing down. Glyphs are assumed to be of equal
+/// width and lines to wrap evenly at `max_width`.
+pub fn text_bounds(text: &TextContent) -> (f32, f32, f32, f32) {
+    let font_size = text.font_size as f32;
+    let full_width = text.content.chars().count().max(1) as f32 * font_size * TEXT_CHAR_WIDTH;
+    let (width, lines) = match text.max_width {
+        Some(max_width) if full_width > max_width as f32 => (max_width as f32, (full_width / max_width as f32).ceil()),
+        _ => (full_width, 1.0),
+    };
+    let left = match text.align {
+        TextAlign::Left => 0.0,
+        TextAlign::Center => -width / 2.0,
+        TextAlign::Right => -width,
+    };
+    let top = -font_size * TEXT_ASCENT;
+    let bottom = top + font_size + (lines - 1.0) * font_size * TEXT_LINE_HEIGHT;
+    (left, top, left + width, bottom)
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use rapier2d::parry::bounding_volume::BoundingVolume;
+use rapier2d::prelude::*;
+
+use crate::shape::{create_shape_obj, pick_shape, shape_isometry, translate_positions, Collidable, Position, ShapeType};
+
+fn create_collider(
+    shape_type: &ShapeType, 
+    positions: &[Position], 
+    sizes: &[u32],
+    rotation: f32,
+) -> Option<(RigidBody, Collider)> {
+    if positions.is_empty() {
+        return None;
+    }
+    
+    let shape = create_shape_obj(shape_type, positions, sizes)?;
+    
+    let rigid_body = RigidBodyBuilder::fixed()
+        .position(shape_isometry(shape_type, positions, rotation))
+        .build();
+        
+    let collider = ColliderBuilder::new(shape).build();
+    
+    Some((rigid_body, collider))
+}
+
+fn build_colliders<T: Collidable>(items: &[T], traversable_check: bool, skip_id: Option<u64>) -> (RigidBodySet, ColliderSet, HashMap<ColliderHandle, u64>) {
+    let mut bodies = RigidBodySet::new();
+    let mut colliders = ColliderSet::new();
+    let mut handle_to_id = HashMap::new();
+    for item in items {
+        if let Some(skip_id) = skip_id {
+            if item.id() == skip_id { continue; }
+        }
+        if traversable_check && item.traversable() {
+            continue;
+        }
+        if let Some((rb, col)) = create_collider(item.shape_type(), item.position(), item.size(), item.rotation()) {
+            let body_handle = bodies.insert(rb);
+            let col_handle = colliders.insert_with_parent(col, body_handle, &mut bodies);
+            handle_to_id.insert(col_handle, item.id());
+        }
+    }
+    (bodies, colliders, handle_to_id)
+}
+
+/// The colliders of one set of items together with a ready query pipeline,
+/// so that repeated queries against the same items only build them once.
+pub struct CollisionWorld {
+    bodies: RigidBodySet,
+    colliders: ColliderSet,
+    pipeline: QueryPipeline,
+    handle_to_id: HashMap<ColliderHandle, u64>,
+}
+
+impl CollisionWorld {
+    pub fn new<T: Collidable>(items: &[T], traversable_check: bool, skip_id: Option<u64>) -> Self {
+        let (bodies, colliders, handle_to_id) = build_colliders(items, traversable_check, skip_id);
+        let mut pipeline = QueryPipeline::new();
+        pipeline.update(&bodies, &colliders);
+        CollisionWorld { bodies, colliders, pipeline, handle_to_id }
+    }
+
+    /// Returns the fraction of `movement` (0.0 to 1.0) the shape can travel
+    /// before it first touches a collider, or `None` if the whole path is clear.
+    pub fn cast_shape(&self, shape: &dyn Shape, iso: &Isometry<Real>, movement: Vector<Real>) -> Option<f32> {
+        // Shapes that already overlap at the start are only reported if the
+        // movement pushes them further together, so a stuck unit can still back out.
+        self.pipeline
+            .cast_shape(
+                &self.bodies,
+                &self.colliders,
+                iso,
+                &movement,
+                shape,
+                1.0,
+                false,
+                QueryFilter::default(),
+            )
+            .map(|(_, toi)| toi.toi)
+    }
+
+    pub fn intersects_shape(&self, shape: &dyn Shape, iso: &Isometry<Real>) -> bool {
+        let mut colliding = false;
+        self.pipeline.intersections_with_shape(
+            &self.bodies,
+            &self.colliders,
+            iso,
+            shape,
+            QueryFilter::default(),
+            |_| {
+                colliding = true;
+                false
+            },
+        );
+        colliding
+    }
+
+    /// How deep the shape sinks into the collider it overlaps most, or zero
+    /// if it only touches or is clear.
+    pub fn penetration(&self, shape: &dyn Shape, iso: &Isometry<Real>) -> f32 {
+        let mut deepest = 0.0_f32;
+        self.pipeline.colliders_with_aabb_intersecting_aabb(&shape.compute_aabb(iso), |handle| {
+            if let Some(collider) = self.colliders.get(*handle) {
+                let contact = rapier2d::parry::query::contact(iso, shape, collider.position(), collider.shape(), 0.0);
+                if let Ok(Some(contact)) = contact {
+                    deepest = deepest.max(-contact.dist);
+                }
+            }
+            true
+        });
+        deepest
+    }
+
+    /// Returns the ids of all items within `distance` of the shape.
+    pub fn items_within(&self, shape: &dyn Shape, iso: &Isometry<Real>, distance: f32) -> Vec<u64> {
+        let aabb = shape.compute_aabb(iso).loosened(distance);
+        let mut found = Vec::new();
+        self.pipeline.colliders_with_aabb_intersecting_aabb(&aabb, |handle| {
+            if let (Some(collider), Some(&item_id)) = (self.colliders.get(*handle), self.handle_to_id.get(handle)) {
+                let gap = rapier2d::parry::query::distance(collider.position(), collider.shape(), iso, shape);
+                if gap.is_ok_and(|gap| gap <= distance) {
+                    found.push(item_id);
+                }
+            }
+            true
+        });
+        found
+    }
+
+    pub fn item_at_point(&self, x: u32, y: u32) -> Option<u64> {
+        let click_point = Point::new(x as f32, y as f32);
+        let mut found_item_id = None;
+        self.pipeline.intersections_with_point(
+            &self.bodies,
+            &self.colliders,
+            &click_point,
+            QueryFilter::default(),
+            |handle| {
+                if let Some(&item_id) = self.handle_to_id.get(&handle) {
+                    found_item_id = Some(item_id);
+                    false
+                } else {
+                    true
+                }
+            },
+        );
+        found_item_id
+    }
+
+    /// Returns true if the segment from `from` to `to` touches any collider.
+    pub fn segment_hits(&self, from: Point<Real>, to: Point<Real>) -> bool {
+        let ray = Ray::new(from, to - from);
+        self.pipeline
+            .cast_ray(&self.bodies, &self.colliders, &ray, 1.0, true, QueryFilter::default())
+            .is_some()
+    }
+}
+
+/// Returns the id of the topmost item whose pick shape contains the point.
+/// Items later in the slice are drawn over earlier ones, so they win.
+pub fn find_item_at_point<T: Collidable>(items: &[T], x: u32, y: u32) -> Option<u64> {
+    let point = Point::new(x as f32, y as f32);
+    items
+        .iter()
+        .rev()
+        .find(|item| pick_shape(*item).is_some_and(|(shape, iso)| shape.contains_point(&iso, &point)))
+        .map(|item| item.id())
+}
+
+/// Returns true if the shape would overlap any collider in the given worlds.
+pub fn shape_overlaps(shape_type: &ShapeType, positions: &[Position], sizes: &[u32], rotation: f32, worlds: &[&CollisionWorld]) -> bool {
+    let Some(shape) = create_shape_obj(shape_type, positions, sizes) else {
+        return false;
+    };
+    let iso = shape_isometry(shape_type, positions, rotation);
+    worlds.iter().any(|world| world.intersects_shape(&*shape, &iso))
+}
+
+/// How much deeper than it started a swept piece may end up in something.
+const SWEEP_TOLERANCE: f32 = 0.01;
+
+/// Sweeps a piece by (dx, dy) against the given worlds and returns its
+/// positions at the first contact along the way, or `None` if it cannot move.
+pub fn sweep_piece(item: &dyn Collidable, dx: i32, dy: i32, worlds: &[&CollisionWorld]) -> Option<Vec<Position>> {
+    if dx == 0 && dy == 0 {
+        return None;
+    }
+    let Some(shape) = create_shape_obj(item.shape_type(), item.position(), item.size()) else {
+        return Some(translate_positions(item.position(), dx, dy));
+    };
+    let iso = shape_isometry(item.shape_type(), item.position(), item.rotation());
+    let movement = vector![dx as f32, dy as f32];
+    let fraction = worlds
+        .iter()
+        .filter_map(|world| world.cast_shape(&*shape, &iso, movement))
+        .fold(1.0_f32, f32::min);
+
+    // Positions are whole numbers, so the stop is rounded back along the path.
+    // Near a curved or slanted edge the rounded point can still land just
+    // inside, in which case it steps back further.
+    let start_depth = worlds.iter().map(|world| world.penetration(&*shape, &iso)).fold(0.0, f32::max);
+    let length = i64::from(dx.unsigned_abs().max(dy.unsigned_abs()));
+    let mut steps = ((length as f32 * fraction).trunc() as i64).min(length);
+    while steps > 0 {
+        let (step_x, step_y) = ((i64::from(dx) * steps / length) as i32, (i64::from(dy) * steps / length) as i32);
+        let moved = Isometry::translation(step_x as f32, step_y as f32) * iso;
+        if worlds.iter().all(|world| world.penetration(&*shape, &moved) <= start_depth + SWEEP_TOLERANCE) {
+            return Some(translate_positions(item.position(), step_x, step_y));
+        }
+        steps -= 1;
+    }
+    None
+}
@@ -0,0 +1,115 @@
+#![allow(dead_code)]
+
+use tabletap_geometry::rapier2d::parry::query::contact;
+use tabletap_geometry::{create_shape_obj, shape_isometry, Collidable, Position, ShapeType, TextAlign, TextContent};
+
+pub const ALL_SHAPES: [ShapeType; 5] = [
+    ShapeType::Circle,
+    ShapeType::Rectangle,
+    ShapeType::Line,
+    ShapeType::Polygon,
+    ShapeType::Text,
+];
+
+/// A bare piece for driving the geometry API without a database row.
+#[derive(Clone, Debug)]
+pub struct Piece {
+    pub id: u64,
+    pub shape_type: ShapeType,
+    pub position: Vec<Position>,
+    pub size: Vec<u32>,
+    pub rotation: f32,
+    pub traversable: bool,
+    pub text: Option<TextContent>,
+}
+
+impl Collidable for Piece {
+    fn id(&self) -> u64 { self.id }
+    fn shape_type(&self) -> &ShapeType { &self.shape_type }
+    fn position(&self) -> &[Position] { &self.position }
+    fn size(&self) -> &[u32] { &self.size }
+    fn rotation(&self) -> f32 { self.rotation }
+    fn traversable(&self) -> bool { self.traversable }
+    fn text(&self) -> Option<&TextContent> { self.text.as_ref() }
+}
+
+impl Piece {
+    pub fn new(id: u64, shape_type: ShapeType, position: &[(u32, u32)], size: &[u32]) -> Self {
+        Piece {
+            id,
+            shape_type,
+            position: position.iter().map(|&(x, y)| Position { x, y }).collect(),
+            size: size.to_vec(),
+            rotation: 0.0,
+            traversable: false,
+            text: None,
+        }
+    }
+
+    pub fn rotated(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    pub fn traversable(mut self) -> Self {
+        self.traversable = true;
+        self
+    }
+}
+
+pub fn circle(id: u64, x: u32, y: u32, diameter: u32) -> Piece {
+    Piece::new(id, ShapeType::Circle, &[(x, y)], &[diameter])
+}
+
+pub fn rectangle(id: u64, x: u32, y: u32, width: u32, height: u32) -> Piece {
+    Piece::new(id, ShapeType::Rectangle, &[(x, y)], &[width, height])
+}
+
+pub fn line(id: u64, from: (u32, u32), to: (u32, u32)) -> Piece {
+    Piece::new(id, ShapeType::Line, &[from, to], &[1])
+}
+
+pub fn thick_line(id: u64, from: (u32, u32), to: (u32, u32), thickness: u32) -> Piece {
+    Piece::new(id, ShapeType::Line, &[from, to], &[thickness])
+}
+
+pub fn polygon(id: u64, vertices: &[(u32, u32)]) -> Piece {
+    Piece::new(id, ShapeType::Polygon, vertices, &[1])
+}
+
+pub fn text(id: u64, x: u32, y: u32) -> Piece {
+    labelled(id, x, y, 12, "Objective")
+}
+
+pub fn labelled(id: u64, x: u32, y: u32, font_size: u32, content: &str) -> Piece {
+    Piece {
+        text: Some(TextContent { content: content.to_string(), font_size, align: TextAlign::Left, max_width: None }),
+        ..Piece::new(id, ShapeType::Text, &[(x, y)], &[font_size])
+    }
+}
+
+/// A piece of the given kind roughly 20 units across, centered on (x, y).
+pub fn sample(id: u64, shape_type: ShapeType, x: u32, y: u32) -> Piece {
+    match shape_type {
+        ShapeType::Circle => circle(id, x, y, 20),
+        ShapeType::Rectangle => rectangle(id, x, y, 20, 20),
+        ShapeType::Line => line(id, (x, y - 10), (x, y + 10)),
+        ShapeType::Polygon => polygon(id, &[(x - 10, y), (x, y - 10), (x + 10, y), (x, y + 10)]),
+        ShapeType::Text => text(id, x, y),
+    }
+}
+
+/// How far two pieces sink into each other; zero when apart or touching.
+pub fn penetration(a: &Piece, b: &Piece) -> f32 {
+    let (Some(shape_a), Some(shape_b)) = (
+        create_shape_obj(&a.shape_type, &a.position, &a.size),
+        create_shape_obj(&b.shape_type, &b.position, &b.size),
+    ) else {
+        return 0.0;
+    };
+    let iso_a = shape_isometry(&a.shape_type, &a.position, a.rotation);
+    let iso_b = shape_isometry(&b.shape_type, &b.position, b.rotation);
+    contact(&iso_a, &*shape_a, &iso_b, &*shape_b, 0.0)
+        .unwrap()
+        .map_or(0.0, |contact| (-contact.dist).max(0.0))
+}
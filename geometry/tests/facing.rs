@@ -0,0 +1,67 @@
+mod common;
+
+use common::*;
+use tabletap_geometry::rapier2d::prelude::{Point, Real};
+use tabletap_geometry::*;
+
+/// A point 50 units from (100, 100), `bearing` degrees clockwise from the
+/// positive x axis.
+fn toward(bearing: f32) -> Point<Real> {
+    let angle = bearing.to_radians();
+    Point::new(100.0 + 50.0 * angle.cos(), 100.0 + 50.0 * angle.sin())
+}
+
+#[test]
+fn quarters_around_an_unrotated_piece() {
+    let unit = circle(1, 100, 100, 20);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(150.0, 100.0)), FacingArc::Front);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(100.0, 150.0)), FacingArc::Flank);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(50.0, 100.0)), FacingArc::Rear);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(100.0, 50.0)), FacingArc::Flank);
+    // A point on the center has no bearing and counts as in front
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(100.0, 100.0)), FacingArc::Front);
+}
+
+#[test]
+fn boundaries_belong_to_front_and_rear() {
+    let unit = circle(1, 100, 100, 20);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(150.0, 150.0)), FacingArc::Front);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(150.0, 50.0)), FacingArc::Front);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(50.0, 150.0)), FacingArc::Rear);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(50.0, 50.0)), FacingArc::Rear);
+}
+
+#[test]
+fn arcs_turn_with_the_piece() {
+    // Facing down the board
+    let unit = circle(1, 100, 100, 20).rotated(90.0);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(100.0, 150.0)), FacingArc::Front);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(150.0, 100.0)), FacingArc::Flank);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(100.0, 50.0)), FacingArc::Rear);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(50.0, 150.0)), FacingArc::Front);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, Point::new(150.0, 50.0)), FacingArc::Rear);
+}
+
+#[test]
+fn uneven_arcs_under_rotation() {
+    let unit = rectangle(1, 100, 100, 40, 20).rotated(30.0);
+    let (front, rear) = (120.0, 60.0);
+    for side in [-1.0, 1.0] {
+        assert_eq!(facing_arc(&unit, front, rear, toward(30.0 + side * 59.0)), FacingArc::Front, "{}", side);
+        assert_eq!(facing_arc(&unit, front, rear, toward(30.0 + side * 61.0)), FacingArc::Flank, "{}", side);
+        assert_eq!(facing_arc(&unit, front, rear, toward(210.0 + side * 31.0)), FacingArc::Flank, "{}", side);
+        assert_eq!(facing_arc(&unit, front, rear, toward(210.0 + side * 29.0)), FacingArc::Rear, "{}", side);
+    }
+}
+
+#[test]
+fn arcs_wrap_around_a_full_turn() {
+    let unit = circle(1, 100, 100, 20).rotated(350.0);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, toward(30.0)), FacingArc::Front);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, toward(40.0)), FacingArc::Flank);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, toward(-60.0)), FacingArc::Flank);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, toward(180.0)), FacingArc::Rear);
+    // Negative rotations face the same way as their positive equivalents
+    let unit = circle(2, 100, 100, 20).rotated(-10.0);
+    assert_eq!(facing_arc(&unit, 90.0, 90.0, toward(30.0)), FacingArc::Front);
+}
@@ -0,0 +1,153 @@
+mod common;
+
+use common::*;
+use proptest::prelude::*;
+use tabletap_geometry::rapier2d::parry::bounding_volume::BoundingVolume;
+use tabletap_geometry::*;
+
+/// Pieces closer than this without sinking in are treated as touching, where
+/// the answer to "do they overlap" depends on rounding.
+const TOUCHING: f32 = 0.01;
+
+fn arb_shape_type() -> impl Strategy<Value = ShapeType> {
+    prop::sample::select(ALL_SHAPES.to_vec())
+}
+
+/// Integer degrees, as the client sends them.
+fn arb_rotation() -> impl Strategy<Value = f32> {
+    (0..360u32).prop_map(|degrees| degrees as f32)
+}
+
+/// A valid piece of the given kind, somewhere in the middle of the board so
+/// nothing is clamped at the edges.
+fn arb_piece(shape_type: ShapeType) -> BoxedStrategy<Piece> {
+    let center = (100..500u32, 100..300u32);
+    match shape_type {
+        ShapeType::Circle => (center, 2..60u32)
+            .prop_map(|((x, y), diameter)| circle(1, x, y, diameter))
+            .boxed(),
+        ShapeType::Rectangle => (center, 2..60u32, 2..60u32, arb_rotation())
+            .prop_map(|((x, y), width, height, rotation)| rectangle(1, x, y, width, height).rotated(rotation))
+            .boxed(),
+        ShapeType::Line => (center, -40..40i32, -40..40i32, arb_rotation())
+            .prop_filter("a line needs two different ends", |(_, dx, dy, _)| (*dx, *dy) != (0, 0))
+            .prop_map(|((x, y), dx, dy, rotation)| {
+                line(1, (x, y), ((x as i32 + dx) as u32, (y as i32 + dy) as u32)).rotated(rotation)
+            })
+            .boxed(),
+        ShapeType::Polygon => (center, 10..40u32, 3..9usize, any::<bool>(), arb_rotation())
+            .prop_map(|((x, y), radius, corners, star, rotation)| {
+                // Stars alternate between two radii, which makes them concave
+                let count = if star { corners * 2 } else { corners };
+                let vertices: Vec<(u32, u32)> = (0..count)
+                    .map(|i| {
+                        let angle = i as f32 / count as f32 * std::f32::consts::TAU;
+                        let r = if star && i % 2 == 1 { radius as f32 / 2.0 } else { radius as f32 };
+                        ((x as f32 + r * angle.cos()).round() as u32, (y as f32 + r * angle.sin()).round() as u32)
+                    })
+                    .collect();
+                polygon(1, &vertices).rotated(rotation)
+            })
+            .prop_filter("rounding can make small outlines degenerate", |piece| validate_polygon(&piece.position).is_ok())
+            .boxed(),
+        ShapeType::Text => center.prop_map(|(x, y)| text(1, x, y)).boxed(),
+    }
+}
+
+fn arb_any_piece() -> impl Strategy<Value = Piece> {
+    arb_shape_type().prop_flat_map(arb_piece)
+}
+
+/// Two pieces with distinct ids, covering every pair of shape types.
+fn arb_pair() -> impl Strategy<Value = (Piece, Piece)> {
+    (arb_any_piece(), arb_any_piece()).prop_map(|(a, b)| (a, Piece { id: 2, ..b }))
+}
+
+fn overlaps(a: &Piece, b: &Piece) -> bool {
+    let world = CollisionWorld::new(std::slice::from_ref(b), true, None);
+    shape_overlaps(&a.shape_type, &a.position, &a.size, a.rotation, &[&world])
+}
+
+/// False for pairs that are just touching, where overlap is ill-defined.
+fn clearly_apart_or_overlapping(a: &Piece, b: &Piece) -> bool {
+    match measure_items(a, b) {
+        Some((gap, _)) => gap > TOUCHING || penetration(a, b) > TOUCHING,
+        None => true,
+    }
+}
+
+fn shifted(piece: &Piece, dx: i32, dy: i32) -> Piece {
+    Piece { position: translate_positions(&piece.position, dx, dy), ..piece.clone() }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    #[test]
+    fn generated_pieces_are_canonical(piece in arb_any_piece()) {
+        prop_assert_eq!(validate_geometry(&piece.shape_type, &piece.position, &piece.size), Ok(()));
+    }
+
+    #[test]
+    fn overlap_is_symmetric((a, b) in arb_pair()) {
+        prop_assume!(clearly_apart_or_overlapping(&a, &b));
+        prop_assert_eq!(overlaps(&a, &b), overlaps(&b, &a));
+    }
+
+    #[test]
+    fn overlap_agrees_with_distance((a, b) in arb_pair()) {
+        prop_assume!(clearly_apart_or_overlapping(&a, &b));
+        match measure_items(&a, &b) {
+            Some((gap, _)) => prop_assert_eq!(overlaps(&a, &b), gap <= TOUCHING),
+            // Text has no shape and never overlaps anything
+            None => prop_assert!(!overlaps(&a, &b)),
+        }
+    }
+
+    #[test]
+    fn overlap_survives_moving_both_pieces((a, b) in arb_pair(), dx in -50..50i32, dy in -50..50i32) {
+        prop_assume!(clearly_apart_or_overlapping(&a, &b));
+        prop_assert_eq!(overlaps(&a, &b), overlaps(&shifted(&a, dx, dy), &shifted(&b, dx, dy)));
+    }
+
+    #[test]
+    fn sweeps_never_end_inside_an_obstacle((a, b) in arb_pair(), dx in -150..150i32, dy in -150..150i32) {
+        prop_assume!(measure_items(&a, &b).is_none_or(|(gap, _)| gap > TOUCHING));
+        let world = CollisionWorld::new(std::slice::from_ref(&b), true, None);
+        if let Some(position) = sweep_piece(&a, dx, dy, &[&world]) {
+            let moved = Piece { position, ..a.clone() };
+            prop_assert!(penetration(&moved, &b) < TOUCHING, "moved to {:?}", moved.position);
+        }
+    }
+
+    #[test]
+    fn sweeps_through_empty_space_go_all_the_way(piece in arb_any_piece(), dx in -50..50i32, dy in -50..50i32) {
+        prop_assume!((dx, dy) != (0, 0));
+        let empty: [Piece; 0] = [];
+        let world = CollisionWorld::new(&empty, true, None);
+        prop_assert_eq!(sweep_piece(&piece, dx, dy, &[&world]), Some(translate_positions(&piece.position, dx, dy)));
+    }
+
+    #[test]
+    fn hit_points_lie_inside_the_bounding_box(piece in arb_any_piece(), x in 50..550u32, y in 50..350u32) {
+        if find_item_at_point(std::slice::from_ref(&piece), x, y).is_some() {
+            let aabb = piece_aabb(&piece).unwrap();
+            prop_assert!(aabb.loosened(TOUCHING).contains_local_point(&point_aabb(x, y).mins));
+        }
+    }
+
+    #[test]
+    fn solid_shapes_are_hit_at_their_center(piece in arb_piece(ShapeType::Circle).prop_union(arb_piece(ShapeType::Rectangle))) {
+        let center = &piece.position[0];
+        prop_assert_eq!(find_item_at_point(std::slice::from_ref(&piece), center.x, center.y), Some(piece.id));
+    }
+
+    #[test]
+    fn grid_cells_contain_the_whole_outline(piece in arb_any_piece()) {
+        let cells: Vec<(i32, i32)> = cells_for_aabb(&swept_aabb(&piece, 0, 0)).collect();
+        for corner in outline_points(&piece.shape_type, &piece.position, &piece.size, piece.rotation) {
+            let cell = ((corner.x / SPATIAL_CELL_SIZE).floor() as i32, (corner.y / SPATIAL_CELL_SIZE).floor() as i32);
+            prop_assert!(cells.contains(&cell), "{:?} is outside {:?}", corner, cells);
+        }
+    }
+}
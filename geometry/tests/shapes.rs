@@ -0,0 +1,334 @@
+mod common;
+
+use common::*;
+use tabletap_geometry::rapier2d::prelude::{Point, Real};
+use tabletap_geometry::*;
+
+fn world(items: &[Piece]) -> CollisionWorld {
+    CollisionWorld::new(items, true, None)
+}
+
+fn overlaps(a: &Piece, b: &Piece) -> bool {
+    shape_overlaps(&a.shape_type, &a.position, &a.size, a.rotation, &[&world(std::slice::from_ref(b))])
+}
+
+fn positions(points: &[(u32, u32)]) -> Vec<Position> {
+    points.iter().map(|&(x, y)| Position { x, y }).collect()
+}
+
+#[test]
+fn validate_geometry_accepts_canonical_forms() {
+    for shape_type in ALL_SHAPES {
+        let piece = sample(1, shape_type, 100, 100);
+        assert_eq!(validate_geometry(&piece.shape_type, &piece.position, &piece.size), Ok(()), "{:?}", shape_type);
+    }
+}
+
+#[test]
+fn validate_geometry_rejects_wrong_counts_and_sizes() {
+    let two_points = positions(&[(10, 10), (20, 20)]);
+    assert!(validate_geometry(&ShapeType::Circle, &two_points, &[10]).is_err());
+    assert!(validate_geometry(&ShapeType::Rectangle, &two_points[..1], &[10]).is_err());
+    assert!(validate_geometry(&ShapeType::Line, &two_points[..1], &[1]).is_err());
+    assert!(validate_geometry(&ShapeType::Text, &two_points[..1], &[]).is_err());
+    assert!(validate_geometry(&ShapeType::Circle, &two_points[..1], &[0]).is_err());
+    assert!(validate_geometry(&ShapeType::Rectangle, &two_points[..1], &[10, 0]).is_err());
+    // Lines and polygons may be drawn with no thickness
+    assert!(validate_geometry(&ShapeType::Line, &two_points, &[0]).is_ok());
+}
+
+#[test]
+fn validate_geometry_rejects_degenerate_lines() {
+    let same = positions(&[(10, 10), (10, 10)]);
+    assert!(validate_geometry(&ShapeType::Line, &same, &[1]).is_err());
+}
+
+#[test]
+fn validate_polygon_rejects_bad_outlines() {
+    assert!(validate_polygon(&positions(&[(0, 0), (10, 0)])).is_err());
+    assert!(validate_polygon(&positions(&[(0, 0), (10, 0), (10, 0), (0, 10)])).is_err());
+    // A bow tie crosses itself
+    assert!(validate_polygon(&positions(&[(0, 0), (10, 10), (10, 0), (0, 10)])).is_err());
+    // Folding back along the same line
+    assert!(validate_polygon(&positions(&[(0, 0), (10, 0), (5, 0), (5, 10)])).is_err());
+    // An L shape is concave but simple
+    assert!(validate_polygon(&positions(&[(0, 0), (20, 0), (20, 10), (10, 10), (10, 20), (0, 20)])).is_ok());
+}
+
+#[test]
+fn validate_geometry_caps_polygon_vertices() {
+    // Vertices on a circle always make a simple convex outline
+    let ring = |n: usize| -> Vec<Position> {
+        (0..n)
+            .map(|i| {
+                let angle = i as f32 / n as f32 * std::f32::consts::TAU;
+                Position { x: (300.0 + 200.0 * angle.cos()).round() as u32, y: (300.0 + 200.0 * angle.sin()).round() as u32 }
+            })
+            .collect()
+    };
+    assert_eq!(validate_geometry(&ShapeType::Polygon, &ring(MAX_POLYGON_POINTS), &[1]), Ok(()));
+    assert!(validate_geometry(&ShapeType::Polygon, &ring(MAX_POLYGON_POINTS + 1), &[1]).is_err());
+}
+
+#[test]
+fn rectangle_from_corners_converts_old_rows() {
+    let corners = positions(&[(10, 20), (50, 40)]);
+    assert_eq!(
+        rectangle_from_corners(&ShapeType::Rectangle, &corners),
+        Some((positions(&[(30, 30)]), vec![40, 20]))
+    );
+    assert_eq!(rectangle_from_corners(&ShapeType::Rectangle, &corners[..1]), None);
+    assert_eq!(rectangle_from_corners(&ShapeType::Line, &corners), None);
+}
+
+#[test]
+fn concave_polygons_are_triangulated_into_the_same_area() {
+    let vertices: Vec<Point<Real>> = [(0.0, 0.0), (20.0, 0.0), (20.0, 10.0), (10.0, 10.0), (10.0, 20.0), (0.0, 20.0)]
+        .iter()
+        .map(|&(x, y)| Point::new(x, y))
+        .collect();
+    assert!(!polygon_is_convex(&vertices));
+    let triangles = triangulate_polygon(&vertices).unwrap();
+    let area: f32 = triangles.iter().map(|[a, b, c]| (b - a).perp(&(c - a)).abs() / 2.0).sum();
+    assert_eq!(area, 300.0);
+}
+
+#[test]
+fn every_shape_pair_overlaps_when_stacked() {
+    for a in ALL_SHAPES {
+        for b in ALL_SHAPES {
+            let expected = a != ShapeType::Text && b != ShapeType::Text;
+            assert_eq!(
+                overlaps(&sample(1, a, 100, 100), &sample(2, b, 100, 100)),
+                expected,
+                "{:?} on {:?}",
+                a,
+                b
+            );
+        }
+    }
+}
+
+#[test]
+fn every_shape_pair_is_clear_when_apart() {
+    for a in ALL_SHAPES {
+        for b in ALL_SHAPES {
+            assert!(!overlaps(&sample(1, a, 100, 100), &sample(2, b, 130, 100)), "{:?} next to {:?}", a, b);
+        }
+    }
+}
+
+#[test]
+fn every_shape_pair_sweeps_to_contact() {
+    for a in ALL_SHAPES {
+        for b in ALL_SHAPES {
+            let mover = sample(1, a, 100, 100);
+            let obstacle = sample(2, b, 200, 100);
+            let moved = sweep_piece(&mover, 200, 0, &[&world(std::slice::from_ref(&obstacle))]).unwrap();
+            let moved = Piece { position: moved, ..mover.clone() };
+            assert!(penetration(&moved, &obstacle) < 1e-3, "{:?} swept into {:?}", a, b);
+            if a == ShapeType::Text || b == ShapeType::Text {
+                assert_eq!(moved.position[0].x, mover.position[0].x + 200, "{:?} and {:?} never collide", a, b);
+            } else {
+                // Rounding the stop point down loses less than one unit of travel
+                let (gap, _) = measure_items(&moved, &obstacle).unwrap();
+                assert!(gap <= 1.0, "{:?} stopped {} short of {:?}", a, gap, b);
+                let pushed = sweep_piece(&moved, 10, 0, &[&world(std::slice::from_ref(&obstacle))]);
+                assert_eq!(pushed, None, "{:?} pushed on into {:?}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn sweep_does_not_move_into_a_touching_piece() {
+    let wall = rectangle(2, 120, 100, 20, 100);
+    let unit = circle(1, 100, 100, 20);
+    assert_eq!(sweep_piece(&unit, 10, 0, &[&world(std::slice::from_ref(&wall))]), None);
+    // Backing away is still allowed
+    assert_eq!(sweep_piece(&unit, -10, 0, &[&world(&[wall])]), Some(positions(&[(90, 100)])));
+}
+
+#[test]
+fn sweep_handles_extreme_offsets() {
+    let unit = circle(1, 100, 100, 20);
+    assert_eq!(sweep_piece(&unit, i32::MIN, 0, &[&world(&[])]), Some(positions(&[(0, 100)])));
+    assert_eq!(sweep_piece(&unit, 0, i32::MAX, &[&world(&[])]), Some(positions(&[(100, 100 + i32::MAX as u32)])));
+}
+
+#[test]
+fn traversable_and_skipped_items_are_left_out() {
+    let forest = rectangle(2, 100, 100, 50, 50).traversable();
+    let unit = circle(1, 100, 100, 10);
+    assert!(!shape_overlaps(&unit.shape_type, &unit.position, &unit.size, 0.0, &[&world(std::slice::from_ref(&forest))]));
+    let all = CollisionWorld::new(&[forest.clone(), unit.clone()], false, Some(1));
+    assert_eq!(all.item_at_point(100, 100), Some(2));
+}
+
+#[test]
+fn find_item_at_point_respects_concave_outlines() {
+    let l_shape = polygon(7, &[(0, 0), (40, 0), (40, 20), (20, 20), (20, 40), (0, 40)]);
+    assert_eq!(find_item_at_point(std::slice::from_ref(&l_shape), 10, 30), Some(7));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&l_shape), 30, 10), Some(7));
+    assert_eq!(find_item_at_point(&[l_shape], 30, 30), None);
+    // Text is picked by the box around its string, which starts at the anchor
+    assert_eq!(find_item_at_point(&[text(3, 50, 50)], 52, 48), Some(3));
+    assert_eq!(find_item_at_point(&[text(3, 50, 50)], 48, 48), None);
+}
+
+#[test]
+fn find_item_at_point_returns_the_topmost_hit() {
+    let below = rectangle(1, 100, 100, 40, 40);
+    let above = circle(2, 110, 100, 20);
+    assert_eq!(find_item_at_point(&[below.clone(), above.clone()], 110, 100), Some(2));
+    assert_eq!(find_item_at_point(&[above.clone(), below.clone()], 110, 100), Some(1));
+    assert_eq!(find_item_at_point(&[below, above], 85, 100), Some(1));
+}
+
+#[test]
+fn text_is_picked_inside_its_approximate_box() {
+    // 10 characters of 20px text: about 120 wide, drawn above the baseline
+    let label = labelled(1, 100, 100, 20, "Objective!");
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 105, 95), Some(1));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 215, 90), Some(1));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 225, 90), None);
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 95, 90), None);
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 105, 110), None);
+    // Text still never takes part in collisions
+    assert!(!overlaps(&label, &rectangle(2, 150, 95, 40, 20)));
+}
+
+#[test]
+fn text_boxes_follow_alignment_and_wrapping() {
+    let mut label = labelled(1, 200, 100, 20, "Objective!");
+    label.text.as_mut().unwrap().align = TextAlign::Center;
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 145, 95), Some(1));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 265, 95), None);
+    label.text.as_mut().unwrap().align = TextAlign::Right;
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 85, 95), Some(1));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 205, 95), None);
+
+    // Wrapped at 50 wide, 120 of text takes three lines
+    label.text.as_mut().unwrap().max_width = Some(50);
+    assert_eq!(text_bounds(label.text.as_ref().unwrap()), (-50.0, -16.0, 0.0, 52.0));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 190, 140), Some(1));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&label), 140, 95), None);
+}
+
+#[test]
+fn validate_text_checks_length_and_font_size() {
+    let text = |content: &str, font_size, max_width| TextContent {
+        content: content.to_string(),
+        font_size,
+        align: TextAlign::Left,
+        max_width,
+    };
+    assert_eq!(validate_text(&text("Hill 203", 16, None), 100), Ok(()));
+    assert!(validate_text(&text("  ", 16, None), 100).is_err());
+    assert!(validate_text(&text(&"x".repeat(MAX_TEXT_LENGTH + 1), 16, None), 100).is_err());
+    assert!(validate_text(&text(&"é".repeat(MAX_TEXT_LENGTH), 16, None), 100).is_ok());
+    assert!(validate_text(&text("Hill", 0, None), 100).is_err());
+    assert!(validate_text(&text("Hill", 101, None), 100).is_err());
+    assert!(validate_text(&text("Hill", 16, Some(0)), 100).is_err());
+}
+
+#[test]
+fn thick_lines_are_picked_across_their_width() {
+    let road = thick_line(1, (100, 100), (200, 100), 10);
+    assert_eq!(find_item_at_point(std::slice::from_ref(&road), 150, 104), Some(1));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&road), 150, 106), None);
+    // The ends are rounded
+    assert_eq!(find_item_at_point(std::slice::from_ref(&road), 204, 100), Some(1));
+    assert_eq!(find_item_at_point(std::slice::from_ref(&road), 204, 104), None);
+    assert!(overlaps(&road, &circle(2, 150, 109, 10)));
+    assert!(!overlaps(&road, &circle(2, 150, 111, 10)));
+}
+
+#[test]
+fn rotation_turns_rectangles_about_their_center() {
+    let bar = rectangle(1, 100, 100, 80, 10);
+    assert_eq!(find_item_at_point(std::slice::from_ref(&bar), 100, 70), None);
+    assert_eq!(find_item_at_point(&[bar.rotated(90.0)], 100, 70), Some(1));
+}
+
+#[test]
+fn outline_points_follow_rotation() {
+    let square = rectangle(1, 100, 100, 20, 20).rotated(45.0);
+    let corners = outline_points(&square.shape_type, &square.position, &square.size, square.rotation);
+    assert_eq!(corners.len(), 4);
+    for corner in corners {
+        let offset = corner - Point::new(100.0, 100.0);
+        assert!((offset.norm() - 200.0_f32.sqrt()).abs() < 1e-3);
+        // At 45 degrees every corner sits on an axis through the center
+        assert!(offset.x.abs() < 1e-3 || offset.y.abs() < 1e-3);
+    }
+    assert!(outline_points(&ShapeType::Circle, &positions(&[(0, 0)]), &[10], 0.0).is_empty());
+}
+
+#[test]
+fn measure_items_reports_edge_and_center_distance() {
+    let (edge, center) = measure_items(&circle(1, 100, 100, 20), &circle(2, 150, 100, 10)).unwrap();
+    assert!((edge - 35.0).abs() < 1e-3);
+    assert!((center - 50.0).abs() < 1e-3);
+    assert_eq!(measure_items(&circle(1, 100, 100, 20), &text(2, 150, 100)), None);
+}
+
+#[test]
+fn line_of_sight_is_clear_blocked_or_obscured() {
+    let from = circle(1, 50, 100, 10);
+    let to = circle(2, 250, 100, 10);
+    let empty: [Piece; 0] = [];
+    let check = |terrain: &[Piece]| {
+        line_of_sight(&from, &to, &world(&empty), &world(terrain), &CollisionWorld::new(terrain, false, None))
+    };
+    assert_eq!(check(&[]), LineOfSight::Clear);
+    assert_eq!(check(&[rectangle(3, 150, 100, 20, 200)]), LineOfSight::Blocked);
+    assert_eq!(check(&[rectangle(3, 150, 100, 20, 200).traversable()]), LineOfSight::Obscured);
+    // A wall that covers only the centers still leaves the edges in sight
+    assert_eq!(check(&[rectangle(3, 150, 100, 20, 4)]), LineOfSight::Clear);
+}
+
+#[test]
+fn find_path_goes_around_a_wall() {
+    let unit = circle(1, 50, 200, 10);
+    let wall = rectangle(2, 150, 200, 20, 200);
+    let target = Position { x: 250, y: 200 };
+    let waypoints = find_path(&unit, &target, &[&world(std::slice::from_ref(&wall))], (600, 400)).unwrap();
+    assert_eq!(waypoints.last(), Some(&target));
+    assert!(waypoints.len() > 1);
+
+    // Every leg must be clear of the wall when swept
+    let walls = world(&[wall]);
+    let mut current = unit.clone();
+    for waypoint in &waypoints {
+        let dx = waypoint.x as i32 - current.position[0].x as i32;
+        let dy = waypoint.y as i32 - current.position[0].y as i32;
+        let moved = sweep_piece(&current, dx, dy, &[&walls]).unwrap();
+        assert_eq!(&moved[0], waypoint);
+        current.position = moved;
+    }
+}
+
+#[test]
+fn find_path_fails_when_the_target_is_walled_in() {
+    let unit = circle(1, 50, 50, 10);
+    let walls = [
+        rectangle(2, 300, 150, 100, 10),
+        rectangle(3, 300, 250, 100, 10),
+        rectangle(4, 250, 200, 10, 100),
+        rectangle(5, 350, 200, 10, 100),
+    ];
+    assert_eq!(find_path(&unit, &Position { x: 300, y: 200 }, &[&world(&walls)], (600, 400)), None);
+}
+
+#[test]
+fn grid_cells_cover_the_swept_area() {
+    let unit = circle(1, 45, 45, 10);
+    let aabb = piece_aabb(&unit).unwrap();
+    assert_eq!(cells_for_aabb(&aabb).collect::<Vec<_>>(), vec![(0, 0), (0, 1), (1, 0), (1, 1)]);
+    let swept: Vec<_> = cells_for_aabb(&swept_aabb(&unit, 100, 0)).collect();
+    assert!(swept.contains(&(2, 0)) && swept.contains(&(2, 1)));
+    // Text is indexed by its box, which runs right from the anchor
+    assert_eq!(cells_for_aabb(&swept_aabb(&text(2, 120, 10), 0, 0)).collect::<Vec<_>>(), vec![(2, 0), (3, 0)]);
+    assert_eq!(cells_for_aabb(&point_aabb(49, 50)).collect::<Vec<_>>(), vec![(0, 1)]);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use common::*;
+use tabletap_geometry::*;
+
+/// A bare template for driving the geometry API without a database row.
+struct Area {
+    kind: TemplateKind,
+    origin: Position,
+    size: u32,
+    width: u32,
+    direction: f32,
+}
+
+impl Template for Area {
+    fn kind(&self) -> TemplateKind { self.kind }
+    fn origin(&self) -> &Position { &self.origin }
+    fn size(&self) -> u32 { self.size }
+    fn width(&self) -> u32 { self.width }
+    fn direction(&self) -> f32 { self.direction }
+}
+
+fn area(kind: TemplateKind, size: u32, width: u32, direction: f32) -> Area {
+    Area { kind, origin: Position { x: 100, y: 100 }, size, width, direction }
+}
+
+#[test]
+fn blast_touches_and_covers() {
+    let blast = area(TemplateKind::Blast, 40, 0, 0.0);
+    assert_eq!(template_coverage(&blast, &circle(1, 100, 100, 10)), Some(true));
+    assert_eq!(template_coverage(&blast, &circle(2, 125, 100, 20)), Some(false));
+    assert_eq!(template_coverage(&blast, &circle(3, 140, 100, 10)), None);
+    assert_eq!(template_coverage(&blast, &rectangle(4, 100, 100, 20, 20)), Some(true));
+    // The corners of a 30 x 30 square stick out past a radius of 20
+    assert_eq!(template_coverage(&blast, &rectangle(5, 100, 100, 30, 30)), Some(false));
+}
+
+#[test]
+fn cone_touches_and_covers() {
+    // Spreads from (100, 100) to a far edge between (200, 70) and (200, 130)
+    let cone = area(TemplateKind::Cone, 100, 60, 0.0);
+    assert_eq!(template_coverage(&cone, &circle(1, 170, 100, 10)), Some(true));
+    assert_eq!(template_coverage(&cone, &circle(2, 205, 100, 20)), Some(false));
+    assert_eq!(template_coverage(&cone, &circle(3, 120, 100, 30)), Some(false));
+    assert_eq!(template_coverage(&cone, &circle(4, 100, 150, 10)), None);
+    assert_eq!(template_coverage(&cone, &rectangle(5, 170, 100, 10, 10)), Some(true));
+
+    let backwards = area(TemplateKind::Cone, 100, 60, 180.0);
+    assert_eq!(template_coverage(&backwards, &circle(6, 30, 100, 10)), Some(true));
+    assert_eq!(template_coverage(&backwards, &circle(7, 170, 100, 10)), None);
+}
+
+#[test]
+fn line_touches_and_covers() {
+    // A band from x 100 to 200 between y 90 and 110
+    let line = area(TemplateKind::Line, 100, 20, 0.0);
+    assert_eq!(template_coverage(&line, &circle(1, 150, 100, 10)), Some(true));
+    assert_eq!(template_coverage(&line, &circle(2, 150, 112, 10)), Some(false));
+    assert_eq!(template_coverage(&line, &circle(3, 150, 150, 10)), None);
+    assert_eq!(template_coverage(&line, &rectangle(4, 150, 100, 10, 10)), Some(true));
+    assert_eq!(template_coverage(&line, &rectangle(5, 150, 100, 10, 40)), Some(false));
+    // Turned to lie along the band, the same rectangle fits
+    assert_eq!(template_coverage(&line, &rectangle(6, 150, 100, 10, 40).rotated(90.0)), Some(true));
+
+    let downwards = area(TemplateKind::Line, 100, 20, 90.0);
+    assert_eq!(template_coverage(&downwards, &circle(7, 100, 150, 10)), Some(true));
+    assert_eq!(template_coverage(&downwards, &circle(8, 150, 100, 10)), None);
+}
+
+#[test]
+fn square_touches_and_covers() {
+    // Centered on the origin, from 80 to 120 on both axes
+    let square = area(TemplateKind::Square, 40, 0, 0.0);
+    assert_eq!(template_coverage(&square, &rectangle(1, 100, 100, 20, 20)), Some(true));
+    assert_eq!(template_coverage(&square, &rectangle(2, 125, 100, 20, 20)), Some(false));
+    assert_eq!(template_coverage(&square, &rectangle(3, 150, 100, 20, 20)), None);
+    assert_eq!(template_coverage(&square, &circle(4, 100, 100, 40)), Some(true));
+    assert_eq!(template_coverage(&square, &circle(5, 100, 100, 42)), Some(false));
+
+    // Turned by 45 degrees, the corners reach out along the axes instead
+    let diamond = area(TemplateKind::Square, 40, 0, 45.0);
+    assert_eq!(template_coverage(&square, &rectangle(6, 100, 100, 50, 4)), Some(false));
+    assert_eq!(template_coverage(&diamond, &rectangle(7, 100, 100, 50, 4)), Some(true));
+    assert_eq!(template_coverage(&diamond, &rectangle(8, 100, 100, 30, 30)), Some(false));
+    assert_eq!(template_coverage(&diamond, &circle(9, 100, 100, 10)), Some(true));
+}
+
+#[test]
+fn empty_templates_touch_nothing() {
+    for kind in [TemplateKind::Blast, TemplateKind::Cone, TemplateKind::Line, TemplateKind::Square] {
+        assert_eq!(template_coverage(&area(kind, 0, 20, 0.0), &circle(1, 100, 100, 10)), None, "{:?}", kind);
+    }
+}
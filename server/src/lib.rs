@@ -1,11 +1,25 @@
+mod roster;
+
+use roster::parse_roster;
 use spacetimedb::{ReducerContext, Table};
 use spacetimedb::rand::Rng;
 use spacetimedb::Timestamp;
 use spacetimedb::SpacetimeType;
+use spacetimedb::Identity;
+use spacetimedb::ViewContext;
+use rapier2d::parry::bounding_volume::{Aabb, BoundingVolume};
 use rapier2d::prelude::*;
+use tabletap_geometry::{
+    cells_for_aabb, create_shape_obj, create_template_shape, facing_arc, find_item_at_point, find_path, line_of_sight, measure_items,
+    piece_aabb, point_aabb, rectangle_from_corners, shape_center, shape_isometry, shape_overlaps, sight_points,
+    sweep_piece, swept_aabb, template_coverage, translate_positions, validate_geometry, validate_text, Collidable, CollisionWorld, LineOfSight,
+    Position, ShapeType, Template, TemplateKind, TextAlign, TextContent,
+};
 
 const BOARD_WIDTH: u32 = 600;
 const BOARD_HEIGHT: u32 = 400;
+/// Largest diameter, side, thickness or font size a piece may have.
+const MAX_SHAPE_SIZE: u32 = BOARD_WIDTH;
 
 fn border_terrain_lines(game_id: u64) -> Vec<Terrain> {
     vec![
@@ -16,7 +30,12 @@ fn border_terrain_lines(game_id: u64) -> Vec<Terrain> {
             size: vec![1],
             color: "rgba(0,0,0,1)".to_string(),
             position: vec![Position { x: 0, y: 0 }, Position { x: BOARD_WIDTH, y: 0 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: false,
+            kind: TerrainKind::Static,
         },
         Terrain {
             id: 0,
@@ -25,7 +44,12 @@ fn border_terrain_lines(game_id: u64) -> Vec<Terrain> {
             size: vec![1],
             color: "rgba(0,0,0,1)".to_string(),
             position: vec![Position { x: BOARD_WIDTH, y: 0 }, Position { x: BOARD_WIDTH, y: BOARD_HEIGHT }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: false,
+            kind: TerrainKind::Static,
         },
         Terrain {
             id: 0,
@@ -34,7 +58,12 @@ fn border_terrain_lines(game_id: u64) -> Vec<Terrain> {
             size: vec![1],
             color: "rgba(0,0,0,1)".to_string(),
             position: vec![Position { x: BOARD_WIDTH, y: BOARD_HEIGHT }, Position { x: 0, y: BOARD_HEIGHT }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: false,
+            kind: TerrainKind::Static,
         },
         Terrain {
             id: 0,
@@ -43,21 +72,20 @@ fn border_terrain_lines(game_id: u64) -> Vec<Terrain> {
             size: vec![1],
             color: "rgba(0,0,0,1)".to_string(),
             position: vec![Position { x: 0, y: BOARD_HEIGHT }, Position { x: 0, y: 0 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: false,
+            kind: TerrainKind::Static,
         },
     ]
 }
 
-trait Collidable {
-    fn id(&self) -> u64;
-    fn shape_type(&self) -> &ShapeType;
-    fn position(&self) -> &Vec<Position>;
-    fn size(&self) -> &Vec<u32>;
-    fn traversable(&self) -> bool;
-}
-
 #[derive(Clone, Debug)]
-#[spacetimedb::table(name = unit, public)]
+/// Private so fog of war and visibility flags can hide units; clients read
+/// the `visible_units` view instead.
+#[spacetimedb::table(name = unit)]
 pub struct Unit {
     #[auto_inc]
     #[primary_key]
@@ -68,18 +96,35 @@ pub struct Unit {
     size: Vec<u32>,
     color: String,
     position: Vec<Position>,
+    /// Clockwise rotation in degrees about the shape center
+    rotation: f32,
+    /// Drawing order within the layer; higher values are drawn and picked on top
+    z_order: i32,
+    /// Player who placed the piece; none for pieces the module set up
+    owner: Option<Identity>,
+    visibility: Visibility,
+    /// Label shown on the piece; required for Text shapes
+    text: Option<TextContent>,
+    /// Side the unit fights for; while set, `color` follows the faction's
+    faction_id: Option<u64>,
+    squad_id: Option<u64>,
+    /// How far, edge to edge, the unit can see under fog of war
+    vision_radius: u32,
 }
 
 impl Collidable for Unit {
     fn id(&self) -> u64 { self.id }
     fn shape_type(&self) -> &ShapeType { &self.shape_type }
-    fn position(&self) -> &Vec<Position> { &self.position }
-    fn size(&self) -> &Vec<u32> { &self.size }
+    fn position(&self) -> &[Position] { &self.position }
+    fn size(&self) -> &[u32] { &self.size }
+    fn rotation(&self) -> f32 { self.rotation }
     fn traversable(&self) -> bool { false }
+    fn text(&self) -> Option<&TextContent> { self.text.as_ref() }
 }
 
+/// Private so hidden pieces stay hidden; clients read the `visible_terrains` view.
 #[derive(Clone, Debug)]
-#[spacetimedb::table(name = terrain, public)]
+#[spacetimedb::table(name = terrain)]
 pub struct Terrain {
     #[auto_inc]
     #[primary_key]
@@ -90,14 +135,24 @@ pub struct Terrain {
     size: Vec<u32>,
     color: String,
     position: Vec<Position>,
+    /// Clockwise rotation in degrees about the shape center
+    rotation: f32,
+    /// Drawing order within the layer; higher values are drawn and picked on top
+    z_order: i32,
+    /// Player who placed the piece; none for pieces the module set up
+    owner: Option<Identity>,
+    visibility: Visibility,
     traversable: bool,
+    /// Doors, gates and bridges can be opened and closed with `toggle_terrain`
+    kind: TerrainKind,
 }
 
 impl Collidable for Terrain {
     fn id(&self) -> u64 { self.id }
     fn shape_type(&self) -> &ShapeType { &self.shape_type }
-    fn position(&self) -> &Vec<Position> { &self.position }
-    fn size(&self) -> &Vec<u32> { &self.size }
+    fn position(&self) -> &[Position] { &self.position }
+    fn size(&self) -> &[u32] { &self.size }
+    fn rotation(&self) -> f32 { self.rotation }
     fn traversable(&self) -> bool { self.traversable }
 }
 
@@ -123,6 +178,18 @@ pub struct Action {
     game_state: Option<GameState>,
 }
 
+/// Records something that happened in a game, without a state snapshot.
+fn log_action(ctx: &ReducerContext, game_id: u64, action_type: &str, description: String) {
+    ctx.db.action().insert(Action {
+        id: 0,
+        game_id,
+        timestamp: ctx.timestamp,
+        action_type: action_type.to_string(),
+        description,
+        game_state: None,
+    });
+}
+
 #[spacetimedb::table(name = games, public)]
 pub struct Game {
     #[auto_inc]
@@ -130,10 +197,20 @@ pub struct Game {
     id: u64,
     name: String,
     description: String,
+    /// Current round, starting at 1
+    round: u32,
+    /// What happens to moves that break a squad's coherency
+    coherency_mode: CoherencyMode,
+    /// Whether players only see enemy units their faction has in sight
+    #[index(btree)]
+    fog_of_war: bool,
+    /// Game master, who sees every piece and decides what is revealed
+    gm: Option<Identity>,
 }
 
+/// Private so hidden pieces stay hidden; clients read the `visible_underlays` view.
 #[derive(Clone, Debug)]
-#[spacetimedb::table(name = underlay, public)]
+#[spacetimedb::table(name = underlay)]
 pub struct Underlay {
     #[auto_inc]
     #[primary_key]
@@ -144,10 +221,27 @@ pub struct Underlay {
     size: Vec<u32>,
     color: String,
     position: Vec<Position>,
+    /// Clockwise rotation in degrees about the shape center
+    rotation: f32,
+    /// Drawing order within the layer; higher values are drawn and picked on top
+    z_order: i32,
+    /// Player who placed the piece; none for pieces the module set up
+    owner: Option<Identity>,
+    visibility: Visibility,
+}
+
+impl Collidable for Underlay {
+    fn id(&self) -> u64 { self.id }
+    fn shape_type(&self) -> &ShapeType { &self.shape_type }
+    fn position(&self) -> &[Position] { &self.position }
+    fn size(&self) -> &[u32] { &self.size }
+    fn rotation(&self) -> f32 { self.rotation }
+    fn traversable(&self) -> bool { true }
 }
 
+/// Private so hidden pieces stay hidden; clients read the `visible_overlays` view.
 #[derive(Clone, Debug)]
-#[spacetimedb::table(name = overlay, public)]
+#[spacetimedb::table(name = overlay)]
 pub struct Overlay {
     #[auto_inc]
     #[primary_key]
@@ -158,180 +252,327 @@ pub struct Overlay {
     size: Vec<u32>,
     color: String,
     position: Vec<Position>,
+    /// Clockwise rotation in degrees about the shape center
+    rotation: f32,
+    /// Drawing order within the layer; higher values are drawn and picked on top
+    z_order: i32,
+    /// Player who placed the piece; none for pieces the module set up
+    owner: Option<Identity>,
+    visibility: Visibility,
+    /// Label shown on the piece; required for Text shapes
+    text: Option<TextContent>,
 }
 
-#[derive(SpacetimeType, Clone, Debug)]
-pub struct Position {
-    x: u32,
-    y: u32,
+impl Collidable for Overlay {
+    fn id(&self) -> u64 { self.id }
+    fn shape_type(&self) -> &ShapeType { &self.shape_type }
+    fn position(&self) -> &[Position] { &self.position }
+    fn size(&self) -> &[u32] { &self.size }
+    fn rotation(&self) -> f32 { self.rotation }
+    fn traversable(&self) -> bool { true }
+    fn text(&self) -> Option<&TextContent> { self.text.as_ref() }
 }
 
-#[derive(SpacetimeType, Copy, Clone, Debug)]
-pub enum ShapeType {
-    Circle,
-    Rectangle,
-    Line,
-    Polygon,
-    Text,
+/// Accepts hex colors (`#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`), the
+/// `rgb()`, `rgba()`, `hsl()` and `hsla()` functions with three or four
+/// numeric arguments, and plain color names such as `red` or `transparent`.
+/// Names are only checked for being a single word, not against the CSS list.
+fn validate_color(color: &str) -> Result<(), String> {
+    let color = color.trim();
+    let valid = if let Some(hex) = color.strip_prefix('#') {
+        matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+    } else if let Some((function, rest)) = color.split_once('(') {
+        let arguments: Vec<&str> = match rest.strip_suffix(')') {
+            Some(inner) => inner
+                .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+                .filter(|argument| !argument.is_empty())
+                .collect(),
+            None => Vec::new(),
+        };
+        matches!(function.trim().to_ascii_lowercase().as_str(), "rgb" | "rgba" | "hsl" | "hsla")
+            && matches!(arguments.len(), 3 | 4)
+            && arguments.iter().all(|argument| {
+                argument
+                    .trim_end_matches('%')
+                    .trim_end_matches("deg")
+                    .parse::<f32>()
+                    .is_ok()
+            })
+    } else {
+        !color.is_empty() && color.chars().all(|c| c.is_ascii_alphabetic())
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("\"{}\" is not a valid CSS color", color))
+    }
 }
 
-fn create_shape_obj(shape_type: &ShapeType, positions: &[Position], sizes: &[u32]) -> Option<SharedShape> {
-    if positions.is_empty() {
-        return None;
+/// Checks everything a newly placed piece must satisfy on its own: canonical
+/// geometry, size limits, a valid color and lying entirely on the board.
+fn validate_piece(shape_type: &ShapeType, positions: &[Position], sizes: &[u32], rotation: f32, color: &str) -> Result<(), String> {
+    validate_geometry(shape_type, positions, sizes)?;
+    if let Some(size) = sizes.iter().find(|&&size| size > MAX_SHAPE_SIZE) {
+        return Err(format!("Size {} is larger than the maximum of {}", size, MAX_SHAPE_SIZE));
+    }
+    validate_color(color)?;
+    if !rotation.is_finite() {
+        return Err(format!("Rotation {} is not a number of degrees", rotation));
     }
 
-    match shape_type {
-        ShapeType::Circle => {
-            if sizes.is_empty() {
-                return None;
-            }
-            let radius = sizes[0] as f32 / 2.0;
-            Some(SharedShape::ball(radius))
-        },
-        ShapeType::Rectangle => {
-            if sizes.len() < 2 {
-                return None;
-            }
-            Some(SharedShape::cuboid(sizes[0] as f32 / 2.0, sizes[1] as f32 / 2.0))
-        },
-        ShapeType::Polygon => {
-            if positions.len() < 3 {
-                return None; // Need at least 3 points for a polygon
-            }
-            
-            let mut vertices = Vec::with_capacity(positions.len());
-            for pos in positions {
-                vertices.push(Point::new(pos.x as f32, pos.y as f32));
-            }
-            
-            // Convert the vertices to a convex polygon shape
-            match SharedShape::convex_polyline(vertices) {
-                Some(shape) => Some(shape),
-                None => None,
-            }
-        },
-        ShapeType::Line => {
-            if positions.len() < 2 {
-                return None;
-            }
-            let p1 = Point::new(positions[0].x as f32, positions[0].y as f32);
-            let p2 = Point::new(positions[1].x as f32, positions[1].y as f32);
-            Some(SharedShape::segment(p1, p2))
-        },
-        _ => None, // Text shapes don't have collisions
+    let off_board = |x: f32, y: f32| x < 0.0 || y < 0.0 || x > BOARD_WIDTH as f32 || y > BOARD_HEIGHT as f32;
+    if let Some(p) = positions.iter().find(|p| off_board(p.x as f32, p.y as f32)) {
+        return Err(format!("Position ({}, {}) is outside the {}x{} board", p.x, p.y, BOARD_WIDTH, BOARD_HEIGHT));
+    }
+    if let Some(shape) = create_shape_obj(shape_type, positions, sizes) {
+        let aabb = shape.compute_aabb(&shape_isometry(shape_type, positions, rotation));
+        // Lines may overhang by half their thickness so they can run along an edge
+        let slack = match shape_type {
+            ShapeType::Line => sizes[0] as f32 / 2.0,
+            _ => 0.0,
+        };
+        if off_board(aabb.mins.x + slack, aabb.mins.y + slack) || off_board(aabb.maxs.x - slack, aabb.maxs.y - slack) {
+            return Err(format!("{:?} extends past the edge of the board", shape_type));
+        }
     }
+    Ok(())
 }
 
-fn create_collider(
-    shape_type: &ShapeType, 
-    positions: &[Position], 
-    sizes: &[u32]
-) -> Option<(RigidBody, Collider)> {
-    if positions.is_empty() {
-        return None;
+/// Checks the label of a unit or overlay. Text shapes have nothing to draw
+/// without one, so for them it is required, and their size is the font size.
+fn validate_label(shape_type: &ShapeType, size: &[u32], text: Option<&TextContent>) -> Result<(), String> {
+    match (shape_type, text) {
+        (ShapeType::Text, Some(text)) if size.first() != Some(&text.font_size) => {
+            Err(format!("Text shapes are sized by their font size of {}, got {:?}", text.font_size, size))
+        }
+        (_, Some(text)) => validate_text(text, MAX_SHAPE_SIZE),
+        (ShapeType::Text, None) => Err("Text shapes need text content".to_string()),
+        (_, None) => Ok(()),
     }
-    
-    let shape = create_shape_obj(shape_type, positions, sizes)?;
-    
-    let position = match shape_type {
-        ShapeType::Circle => {
-            let x = positions[0].x as f32;
-            let y = positions[0].y as f32;
-            Isometry::translation(x, y)
-        },
-        ShapeType::Rectangle => {
-            let x = positions[0].x as f32;
-            let y = positions[0].y as f32;
-            Isometry::translation(x, y)
-        },
-        ShapeType::Polygon | ShapeType::Line => {
-            Isometry::identity()
-        },
-        _ => Isometry::identity(),
-    };
-    
-    let rigid_body = RigidBodyBuilder::fixed()
-        .position(position)
-        .build();
-        
-    let collider = ColliderBuilder::new(shape).build();
-    
-    Some((rigid_body, collider))
 }
 
-fn check_shape_collision_items<T: Collidable>(
-    moving_shape_type: &ShapeType,
-    moving_pos: &[Position],
-    moving_size: &[u32],
-    items: &[T],
-    skip_id: Option<u64>, // Optionally skip a unit (e.g. the moving one itself)
-) -> bool {
-    let (bodies, colliders, _) = build_colliders(items, true, skip_id);
-    
-    // Prepare the moving shape
-    let moving_shape = match create_shape_obj(moving_shape_type, moving_pos, moving_size) {
-        Some(s) => s,
-        None => return false,
-    };
-    let moving_iso = Isometry::new(vector![moving_pos[0].x as f32, moving_pos[0].y as f32], 0.0);
-    let mut pipeline = QueryPipeline::new();
-    pipeline.update(&bodies, &colliders);
-    let mut colliding = false;
-    pipeline.intersections_with_shape(
-        &bodies,
-        &colliders,
-        &moving_iso,
-        &*moving_shape,
-        QueryFilter::default(),
-        |_| {
-            colliding = true;
-            false
-        },
-    );
-    colliding
+/// The size a piece takes on with a new label; Text shapes follow the font.
+fn label_size(shape_type: &ShapeType, size: Vec<u32>, text: Option<&TextContent>) -> Vec<u32> {
+    match (shape_type, text) {
+        (ShapeType::Text, Some(text)) => vec![text.font_size],
+        _ => size,
+    }
 }
 
-fn build_colliders<T: Collidable>(items: &[T], traversable_check: bool, skip_id: Option<u64>) -> (RigidBodySet, ColliderSet, std::collections::HashMap<rapier2d::prelude::ColliderHandle, u64>) {
-    let mut bodies = RigidBodySet::new();
-    let mut colliders = ColliderSet::new();
-    let mut handle_to_id = std::collections::HashMap::new();
-    for item in items {
-        if let Some(skip_id) = skip_id {
-            if item.id() == skip_id { continue; }
-        }
-        if traversable_check && item.traversable() {
-            continue;
+#[derive(SpacetimeType, Clone, Debug)]
+pub enum FacingTarget {
+    Point(Position),
+    Unit(u64),
+}
+
+/// Arc widths used for units that have no `UnitArcs` row: four equal quarters.
+const DEFAULT_FRONT_ARC: f32 = 90.0;
+const DEFAULT_REAR_ARC: f32 = 90.0;
+
+/// Two units closer than this are in base contact. Drags stop up to a pixel
+/// short of the contact point, so this is a little more than that.
+const CONTACT_DISTANCE: f32 = 1.5;
+
+fn unit_arc_widths(ctx: &ReducerContext, unit_id: u64) -> (f32, f32) {
+    ctx.db
+        .unit_arcs()
+        .unit_id()
+        .find(unit_id)
+        .map_or((DEFAULT_FRONT_ARC, DEFAULT_REAR_ARC), |arcs| (arcs.front, arcs.rear))
+}
+
+#[derive(SpacetimeType, Copy, Clone, Debug, PartialEq)]
+pub enum Layer {
+    Underlay,
+    Terrain,
+    Unit,
+    Overlay,
+}
+
+/// Brings a piece's grid cells in line with its current shape. Rows are
+/// only rewritten when the set of cells changes, which during a drag is rare.
+fn index_piece(ctx: &ReducerContext, game_id: u64, layer: Layer, item: &dyn Collidable) {
+    let cells: std::collections::BTreeSet<(i32, i32)> = piece_aabb(item)
+        .map(|aabb| cells_for_aabb(&aabb).collect())
+        .unwrap_or_default();
+    let existing: Vec<SpatialCell> = ctx
+        .db
+        .spatial_cell()
+        .item_id()
+        .filter(&item.id())
+        .filter(|cell| cell.layer == layer)
+        .collect();
+    if existing.len() == cells.len() && existing.iter().all(|cell| cells.contains(&(cell.cell_x, cell.cell_y))) {
+        return;
+    }
+    for cell in existing {
+        ctx.db.spatial_cell().id().delete(cell.id);
+    }
+    for (cell_x, cell_y) in cells {
+        ctx.db.spatial_cell().insert(SpatialCell {
+            id: 0,
+            game_id,
+            cell_x,
+            cell_y,
+            layer,
+            item_id: item.id(),
+        });
+    }
+}
+
+fn unindex_piece(ctx: &ReducerContext, layer: Layer, item_id: u64) {
+    for cell in ctx.db.spatial_cell().item_id().filter(&item_id) {
+        if cell.layer == layer {
+            ctx.db.spatial_cell().id().delete(cell.id);
         }
-        if let Some((rb, col)) = create_collider(&item.shape_type(), &item.position(), &item.size()) {
-            let body_handle = bodies.insert(rb);
-            let col_handle = colliders.insert_with_parent(col, body_handle, &mut bodies);
-            handle_to_id.insert(col_handle, item.id());
-        }
-    }
-    (bodies, colliders, handle_to_id)
-}
-
-fn find_item_at_point<T: Collidable>(items: &[T], x: u32, y: u32) -> Option<u64> {
-    let (bodies, colliders, handle_to_id) = build_colliders(items, false, None);
-    let mut pipeline = QueryPipeline::new();
-    pipeline.update(&bodies, &colliders);
-    let click_point = Point::new(x as f32, y as f32);
-    let mut found_item_id = None;
-    pipeline.intersections_with_point(
-        &bodies,
-        &colliders,
-        &click_point,
-        QueryFilter::default(),
-        |handle| {
-            if let Some(&item_id) = handle_to_id.get(&handle) {
-                found_item_id = Some(item_id);
-                false
-            } else {
-                true
+    }
+}
+
+/// Ids of the pieces on `layer` whose grid cells overlap the box.
+fn nearby_ids(ctx: &ReducerContext, game_id: u64, layer: Layer, aabb: &Aabb) -> std::collections::BTreeSet<u64> {
+    let mut ids = std::collections::BTreeSet::new();
+    for (cell_x, cell_y) in cells_for_aabb(aabb) {
+        for cell in ctx.db.spatial_cell().game_cell().filter((game_id, cell_x, cell_y)) {
+            if cell.layer == layer {
+                ids.insert(cell.item_id);
             }
-        },
-    );
-    found_item_id
+        }
+    }
+    ids
+}
+
+fn nearby_units(ctx: &ReducerContext, game_id: u64, aabb: &Aabb) -> Vec<Unit> {
+    nearby_ids(ctx, game_id, Layer::Unit, aabb)
+        .into_iter()
+        .filter_map(|id| ctx.db.unit().id().find(id))
+        .collect()
+}
+
+fn nearby_terrains(ctx: &ReducerContext, game_id: u64, aabb: &Aabb) -> Vec<Terrain> {
+    nearby_ids(ctx, game_id, Layer::Terrain, aabb)
+        .into_iter()
+        .filter_map(|id| ctx.db.terrain().id().find(id))
+        .collect()
+}
+
+fn nearby_underlays(ctx: &ReducerContext, game_id: u64, aabb: &Aabb) -> Vec<Underlay> {
+    nearby_ids(ctx, game_id, Layer::Underlay, aabb)
+        .into_iter()
+        .filter_map(|id| ctx.db.underlay().id().find(id))
+        .collect()
+}
+
+fn nearby_overlays(ctx: &ReducerContext, game_id: u64, aabb: &Aabb) -> Vec<Overlay> {
+    nearby_ids(ctx, game_id, Layer::Overlay, aabb)
+        .into_iter()
+        .filter_map(|id| ctx.db.overlay().id().find(id))
+        .collect()
+}
+
+fn insert_unit_row(ctx: &ReducerContext, unit: Unit) -> Unit {
+    let unit = ctx.db.unit().insert(unit);
+    index_piece(ctx, unit.game_id, Layer::Unit, &unit);
+    index_squad_member(ctx, unit.id, unit.squad_id);
+    unit
+}
+
+fn update_unit_row(ctx: &ReducerContext, unit: Unit) -> Unit {
+    index_piece(ctx, unit.game_id, Layer::Unit, &unit);
+    ctx.db.unit().id().update(unit)
+}
+
+fn insert_terrain_row(ctx: &ReducerContext, terrain: Terrain) -> Terrain {
+    let terrain = ctx.db.terrain().insert(terrain);
+    index_piece(ctx, terrain.game_id, Layer::Terrain, &terrain);
+    terrain
+}
+
+fn update_terrain_row(ctx: &ReducerContext, terrain: Terrain) -> Terrain {
+    index_piece(ctx, terrain.game_id, Layer::Terrain, &terrain);
+    ctx.db.terrain().id().update(terrain)
+}
+
+fn remove_terrain(ctx: &ReducerContext, terrain_id: u64) {
+    ctx.db.terrain().id().delete(terrain_id);
+    unindex_piece(ctx, Layer::Terrain, terrain_id);
+}
+
+fn insert_underlay_row(ctx: &ReducerContext, underlay: Underlay) -> Underlay {
+    let underlay = ctx.db.underlay().insert(underlay);
+    index_piece(ctx, underlay.game_id, Layer::Underlay, &underlay);
+    underlay
+}
+
+fn update_underlay_row(ctx: &ReducerContext, underlay: Underlay) -> Underlay {
+    index_piece(ctx, underlay.game_id, Layer::Underlay, &underlay);
+    ctx.db.underlay().id().update(underlay)
+}
+
+fn remove_underlay(ctx: &ReducerContext, underlay_id: u64) {
+    ctx.db.underlay().id().delete(underlay_id);
+    unindex_piece(ctx, Layer::Underlay, underlay_id);
+}
+
+fn insert_overlay_row(ctx: &ReducerContext, overlay: Overlay) -> Overlay {
+    let overlay = ctx.db.overlay().insert(overlay);
+    index_piece(ctx, overlay.game_id, Layer::Overlay, &overlay);
+    overlay
+}
+
+fn update_overlay_row(ctx: &ReducerContext, overlay: Overlay) -> Overlay {
+    index_piece(ctx, overlay.game_id, Layer::Overlay, &overlay);
+    ctx.db.overlay().id().update(overlay)
+}
+
+fn remove_overlay(ctx: &ReducerContext, overlay_id: u64) {
+    ctx.db.overlay().id().delete(overlay_id);
+    unindex_piece(ctx, Layer::Overlay, overlay_id);
+}
+
+/// Puts pieces in drawing order, by z-order and then by age, and returns the
+/// topmost one under the point.
+fn topmost_at_point<T: Collidable>(mut items: Vec<T>, z_order: impl Fn(&T) -> i32, x: u32, y: u32) -> Option<u64> {
+    items.sort_by_key(|item| (z_order(item), item.id()));
+    find_item_at_point(&items, x, y)
+}
+
+/// Layers from the top of the drawing order down, as clicks should find them.
+const PICK_ORDER: [Layer; 4] = [Layer::Overlay, Layer::Unit, Layer::Terrain, Layer::Underlay];
+
+/// Finds the topmost piece under a point, looking only at the given layers
+/// (all of them if empty).
+fn pick(ctx: &ReducerContext, game_id: u64, x: u32, y: u32, layers: &[Layer]) -> Option<(Layer, u64)> {
+    let game = ctx.db.games().id().find(game_id)?;
+    let viewer = Viewer::sender(ctx, &game);
+    let point = point_aabb(x, y);
+    // Pieces the sender can't see can't be clicked either
+    fn visible<T>(items: Vec<T>, sees: impl Fn(&T) -> bool) -> Vec<T> {
+        items.into_iter().filter(sees).collect()
+    }
+    PICK_ORDER
+        .into_iter()
+        .filter(|layer| layers.is_empty() || layers.contains(layer))
+        .find_map(|layer| {
+            let hit = match layer {
+                Layer::Overlay => {
+                    let overlays = visible(nearby_overlays(ctx, game_id, &point), |o| viewer.may_see(o.visibility, o.owner, None));
+                    topmost_at_point(overlays, |o| o.z_order, x, y)
+                }
+                Layer::Unit => {
+                    let units = visible(nearby_units(ctx, game_id, &point), |u| sender_sees_unit(ctx, u));
+                    topmost_at_point(units, |u| u.z_order, x, y)
+                }
+                Layer::Terrain => {
+                    let terrains = visible(nearby_terrains(ctx, game_id, &point), |t| viewer.may_see(t.visibility, t.owner, None));
+                    topmost_at_point(terrains, |t| t.z_order, x, y)
+                }
+                Layer::Underlay => {
+                    let underlays = visible(nearby_underlays(ctx, game_id, &point), |u| viewer.may_see(u.visibility, u.owner, None));
+                    topmost_at_point(underlays, |u| u.z_order, x, y)
+                }
+            };
+            hit.map(|id| (layer, id))
+        })
 }
 
 #[spacetimedb::reducer(init)]
@@ -340,118 +581,188 @@ pub fn init(_ctx: &ReducerContext) {
         id: 0,
         name: "Game 1".to_string(),
         description: "Description 1".to_string(),
+        round: 1,
+        coherency_mode: CoherencyMode::Flag,
+        fog_of_war: false,
+        gm: None,
     });
     _ctx.db.games().insert(Game { 
         id: 0,
         name: "Game 2".to_string(),
         description: "Description 2".to_string(),
+        round: 1,
+        coherency_mode: CoherencyMode::Flag,
+        fog_of_war: false,
+        gm: None,
     });
    // for game1 and grame2 setup a game state
     for  game_id in 0..2 {
-        _ctx.db.unit().insert(Unit { 
+        insert_unit_row(_ctx, Unit { 
             id: 0, 
             game_id,
             shape_type: ShapeType::Circle,
             size: vec![28], 
             color: "blue".to_string(),
             position: vec![Position { x: 50, y: 50 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
+            text: None,
+            faction_id: None,
+            squad_id: None,
+            vision_radius: DEFAULT_VISION_RADIUS,
         });
     
-        _ctx.db.unit().insert(Unit { 
+        insert_unit_row(_ctx, Unit { 
             id: 0, 
             game_id,
             shape_type: ShapeType::Circle,
             size: vec![28], 
             color: "red".to_string(),
             position: vec![Position { x: 150, y: 50 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
+            text: None,
+            faction_id: None,
+            squad_id: None,
+            vision_radius: DEFAULT_VISION_RADIUS,
         });
-        _ctx.db.unit().insert(Unit { 
+        insert_unit_row(_ctx, Unit { 
             id: 0, 
             game_id,
             shape_type: ShapeType::Rectangle,
             size: vec![30, 30],
             color: "yellow".to_string(),
             position: vec![Position { x: 100, y: 100 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
+            text: None,
+            faction_id: None,
+            squad_id: None,
+            vision_radius: DEFAULT_VISION_RADIUS,
         });
         
-        _ctx.db.terrain().insert(Terrain { 
+        insert_terrain_row(_ctx, Terrain { 
             id: 0, 
             game_id,
             shape_type: ShapeType::Rectangle,
             size: vec![150, 100],
             color: "#8fbc8f".to_string(),
-            position: vec![Position { x: 200, y: 250 }, Position { x: 350, y: 350 }],
+            position: vec![Position { x: 275, y: 300 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: true,
+            kind: TerrainKind::Static,
         });
         
-        _ctx.db.terrain().insert(Terrain { 
+        insert_terrain_row(_ctx, Terrain { 
             id: 0, 
             game_id,
             shape_type: ShapeType::Rectangle,
             size: vec![80, 80],
             color: "#8fbc8f".to_string(),
-            position: vec![Position { x: 50, y: 100 }, Position { x: 130, y: 180 }],
+            position: vec![Position { x: 90, y: 140 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: true,
+            kind: TerrainKind::Static,
         });
     
-        _ctx.db.terrain().insert(Terrain { 
+        insert_terrain_row(_ctx, Terrain { 
             id: 0, 
             game_id,
             shape_type: ShapeType::Rectangle,
             size: vec![120, 60],
             color: "#8b4513".to_string(),  
-            position: vec![Position { x: 400, y: 150 }, Position { x: 520, y: 210 }],
+            position: vec![Position { x: 460, y: 180 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: false,
+            kind: TerrainKind::Static,
         });
         
-        _ctx.db.terrain().insert(Terrain { 
+        insert_terrain_row(_ctx, Terrain { 
             id: 0, 
             game_id,
             shape_type: ShapeType::Circle,
             size: vec![50],  
             color: "#8b4513".to_string(),  
             position: vec![Position { x: 100, y: 300 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: false,
+            kind: TerrainKind::Static,
         });
         
-        _ctx.db.terrain().insert(Terrain {
+        insert_terrain_row(_ctx, Terrain {
             id: 0,
             game_id,
             shape_type: ShapeType::Line,
             size: vec![3],
             color: "rgba(255, 0, 0, 0.8)".to_string(),
             position: vec![Position { x: 50, y: 50 }, Position { x: 550, y: 350 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
             traversable: false,
+            kind: TerrainKind::Static,
         });
     
-        _ctx.db.underlay().insert(Underlay {
+        insert_underlay_row(_ctx, Underlay {
             id: 0,
             game_id,
             shape_type: ShapeType::Circle,
             size: vec![100],
             color: "rgba(0, 255, 0, 0.2)".to_string(),
             position: vec![Position { x: 300, y: 300 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
         });
     
-        _ctx.db.underlay().insert(Underlay {
+        insert_underlay_row(_ctx, Underlay {
             id: 0,
             game_id,
             shape_type: ShapeType::Rectangle,
             size: vec![100, 100],
             color: "rgba(255, 165, 0, 0.2)".to_string(),
-            position: vec![Position { x: 100, y: 100 }, Position { x: 200, y: 200 }],
+            position: vec![Position { x: 150, y: 150 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
         });
     
-        _ctx.db.overlay().insert(Overlay {
+        insert_overlay_row(_ctx, Overlay {
             id: 0,
             game_id,
             shape_type: ShapeType::Line,
             size: vec![3],
             color: "rgba(255, 0, 0, 0.8)".to_string(),
             position: vec![Position { x: 50, y: 50 }, Position { x: 550, y: 350 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
+            text: None,
         });
     
-        _ctx.db.overlay().insert(Overlay {
+        insert_overlay_row(_ctx, Overlay {
             id: 0,
             game_id,
             shape_type: ShapeType::Polygon,
@@ -463,19 +774,34 @@ pub fn init(_ctx: &ReducerContext) {
                 Position { x: 500, y: 200 },
                 Position { x: 400, y: 200 }
             ],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
+            text: None,
         });
     
-        _ctx.db.overlay().insert(Overlay {
+        insert_overlay_row(_ctx, Overlay {
             id: 0,
             game_id,
             shape_type: ShapeType::Text,
             size: vec![24],
             color: "rgba(0, 0, 0, 1.0)".to_string(),
             position: vec![Position { x: 250, y: 50 }],
+            rotation: 0.0,
+            z_order: 0,
+            owner: None,
+            visibility: Visibility::Everyone,
+            text: Some(TextContent {
+                content: "TableTap".to_string(),
+                font_size: 24,
+                align: TextAlign::Left,
+                max_width: None,
+            }),
         });
     
         for t in border_terrain_lines(game_id) {
-            _ctx.db.terrain().insert(t);
+            insert_terrain_row(_ctx, t);
         }
     }
 }
@@ -489,34 +815,159 @@ pub fn identity_disconnected(_ctx: &ReducerContext) {
 }
 
 #[spacetimedb::reducer]
-pub fn add_unit(ctx: &ReducerContext, game_id: u64, shape_type: ShapeType, size: Vec<u32>, color: String, position: Vec<Position>) {
-    ctx.db.unit().insert(Unit { 
+#[allow(clippy::too_many_arguments)]
+pub fn add_unit(
+    ctx: &ReducerContext,
+    game_id: u64,
+    shape_type: ShapeType,
+    size: Vec<u32>,
+    color: String,
+    position: Vec<Position>,
+    text: Option<TextContent>,
+    visibility: Option<Visibility>,
+) -> Result<(), String> {
+    validate_piece(&shape_type, &position, &size, 0.0, &color)?;
+    validate_label(&shape_type, &size, text.as_ref())?;
+    let visibility = placement_visibility(ctx, game_id, visibility)?;
+    let unit = Unit { 
         id: 0, 
         game_id,
         shape_type, 
         size, 
         color, 
         position,
-    });
+        rotation: 0.0,
+        z_order: 0,
+        owner: Some(ctx.sender),
+        visibility,
+        text,
+        faction_id: None,
+        squad_id: None,
+        vision_radius: DEFAULT_VISION_RADIUS,
+    };
+    if unit_blocked(ctx, &unit) {
+        return Err("Unit would overlap another unit or impassable terrain".to_string());
+    }
+    insert_unit_row(ctx, unit);
+    refresh_visibility(ctx, game_id);
+    Ok(())
 }
 
 #[spacetimedb::reducer]
-pub fn add_terrain(ctx: &ReducerContext, game_id: u64, shape_type: ShapeType, size: Vec<u32>, color: String, position: Vec<Position>, traversable: bool) {
-    ctx.db.terrain().insert(Terrain { 
+#[allow(clippy::too_many_arguments)]
+pub fn add_terrain(
+    ctx: &ReducerContext,
+    game_id: u64,
+    shape_type: ShapeType,
+    size: Vec<u32>,
+    color: String,
+    position: Vec<Position>,
+    traversable: bool,
+    visibility: Option<Visibility>,
+) -> Result<(), String> {
+    validate_piece(&shape_type, &position, &size, 0.0, &color)?;
+    let visibility = placement_visibility(ctx, game_id, visibility)?;
+    let terrain = Terrain { 
         id: 0,
         game_id,
         shape_type, 
         size, 
         color, 
         position,
+        rotation: 0.0,
+        z_order: 0,
+        owner: Some(ctx.sender),
+        visibility,
         traversable,
-    });
+        kind: TerrainKind::Static,
+    };
+    if terrain_blocks_units(ctx, &terrain) {
+        return Err("Impassable terrain would overlap a unit".to_string());
+    }
+    insert_terrain_row(ctx, terrain);
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+/// Returns true if the unit overlaps another unit or impassable terrain.
+fn unit_blocked(ctx: &ReducerContext, unit: &Unit) -> bool {
+    let area = swept_aabb(unit, 0, 0);
+    let units = nearby_units(ctx, unit.game_id, &area);
+    let terrains = nearby_terrains(ctx, unit.game_id, &area);
+    let worlds = [&CollisionWorld::new(&units, true, Some(unit.id)), &CollisionWorld::new(&terrains, true, None)];
+    shape_overlaps(&unit.shape_type, &unit.position, &unit.size, unit.rotation, &worlds)
+}
+
+/// Returns true if the terrain is impassable and overlaps a unit.
+fn terrain_blocks_units(ctx: &ReducerContext, terrain: &Terrain) -> bool {
+    if terrain.traversable {
+        return false;
+    }
+    let units = nearby_units(ctx, terrain.game_id, &swept_aabb(terrain, 0, 0));
+    shape_overlaps(&terrain.shape_type, &terrain.position, &terrain.size, terrain.rotation, &[&CollisionWorld::new(&units, true, None)])
+}
+
+/// Rewrites rectangles still stored with two corner positions into the
+/// center-and-size form, so their colliders line up with what is drawn.
+#[spacetimedb::reducer]
+pub fn migrate_rectangle_geometry(ctx: &ReducerContext) {
+    for unit in ctx.db.unit().iter() {
+        if let Some((position, size)) = rectangle_from_corners(&unit.shape_type, &unit.position) {
+            update_unit_row(ctx, Unit { position, size, ..unit });
+        }
+    }
+    for terrain in ctx.db.terrain().iter() {
+        if let Some((position, size)) = rectangle_from_corners(&terrain.shape_type, &terrain.position) {
+            update_terrain_row(ctx, Terrain { position, size, ..terrain });
+        }
+    }
+    for underlay in ctx.db.underlay().iter() {
+        if let Some((position, size)) = rectangle_from_corners(&underlay.shape_type, &underlay.position) {
+            update_underlay_row(ctx, Underlay { position, size, ..underlay });
+        }
+    }
+    for overlay in ctx.db.overlay().iter() {
+        if let Some((position, size)) = rectangle_from_corners(&overlay.shape_type, &overlay.position) {
+            update_overlay_row(ctx, Overlay { position, size, ..overlay });
+        }
+    }
+}
+
+/// Deletes a unit together with the rows that only exist for it, including
+/// any units embarked in it.
+fn remove_unit(ctx: &ReducerContext, unit_id: u64) {
+    let squad_id = ctx.db.unit().id().find(unit_id).and_then(|unit| unit.squad_id);
+    ctx.db.unit().id().delete(unit_id);
+    ctx.db.embarked_unit().unit_id().delete(unit_id);
+    for passenger in ctx.db.embarked_unit().carrier_id().filter(&unit_id) {
+        remove_unit(ctx, passenger.unit_id);
+    }
+    unindex_piece(ctx, Layer::Unit, unit_id);
+    index_squad_member(ctx, unit_id, None);
+    ctx.db.planned_path().unit_id().delete(unit_id);
+    ctx.db.unit_arcs().unit_id().delete(unit_id);
+    ctx.db.transport().unit_id().delete(unit_id);
+    for attribute in ctx.db.unit_attribute().unit_id().filter(&unit_id) {
+        ctx.db.unit_attribute().id().delete(attribute.id);
+    }
+    for condition in ctx.db.condition().unit_id().filter(&unit_id) {
+        ctx.db.condition().id().delete(condition.id);
+    }
+    if let Some(squad_id) = squad_id {
+        refresh_squad_coherency(ctx, squad_id);
+    }
 }
 
 #[spacetimedb::reducer]
 pub fn delete_unit(ctx: &ReducerContext, unit_id: u64) {
-    if let Some(_unit) = ctx.db.unit().id().find(unit_id) {
-        ctx.db.unit().id().delete(unit_id);
+    if let Some(unit) = ctx.db.unit().id().find(unit_id).filter(|unit| sender_sees_unit(ctx, unit)) {
+        match check_unit_control(ctx, unit_id) {
+            Ok(()) => {
+                remove_unit(ctx, unit_id);
+                refresh_visibility(ctx, unit.game_id);
+            }
+            Err(error) => log::error!("Failed to delete unit: {}", error),
+        }
     } else {
         log::error!("Failed to delete unit: ID {} not found", unit_id);
     }
@@ -524,35 +975,45 @@ pub fn delete_unit(ctx: &ReducerContext, unit_id: u64) {
 
 #[spacetimedb::reducer]
 pub fn delete_terrain(ctx: &ReducerContext, terrain_id: u64) {
-    if let Some(_terrain) = ctx.db.terrain().id().find(terrain_id) {
-        ctx.db.terrain().id().delete(terrain_id);
+    if let Some(terrain) = ctx.db.terrain().id().find(terrain_id).filter(|t| sender_sees_piece(ctx, t.game_id, t.owner, t.visibility)) {
+        remove_terrain(ctx, terrain_id);
+        refresh_visibility(ctx, terrain.game_id);
     } else {
         log::error!("Failed to delete terrain: ID {} not found", terrain_id);
     }
 }
 
+/// Deletes the topmost piece under the point on any of `layers`, or on any
+/// layer if none are given.
 #[spacetimedb::reducer]
-pub fn delete_at_coordinates(ctx: &ReducerContext, game_id: u64, x: u32, y: u32) {
-    let units: Vec<Unit> = ctx.db.unit().game_id().filter(&game_id).collect();
-    if let Some(unit_id) = find_item_at_point(&units, x, y) {
-        ctx.db.unit().id().delete(unit_id);
-        return;
-    }
-    let terrains: Vec<Terrain> = ctx.db.terrain().game_id().filter(&game_id).collect();
-    if let Some(terrain_id) = find_item_at_point(&terrains, x, y) {
-        ctx.db.terrain().id().delete(terrain_id);
-        return;
+pub fn delete_at_coordinates(ctx: &ReducerContext, game_id: u64, x: u32, y: u32, layers: Vec<Layer>) {
+    match pick(ctx, game_id, x, y, &layers) {
+        Some((Layer::Overlay, id)) => remove_overlay(ctx, id),
+        Some((Layer::Unit, id)) => match check_unit_control(ctx, id) {
+            Ok(()) => {
+                remove_unit(ctx, id);
+                refresh_visibility(ctx, game_id);
+            }
+            Err(error) => log::error!("Failed to delete unit: {}", error),
+        },
+        Some((Layer::Terrain, id)) => {
+            remove_terrain(ctx, id);
+            refresh_visibility(ctx, game_id);
+        }
+        Some((Layer::Underlay, id)) => remove_underlay(ctx, id),
+        None => {}
     }
 }
 
 #[spacetimedb::reducer]
 pub fn delete_all(ctx: &ReducerContext, game_id: u64) {
     for unit in ctx.db.unit().game_id().filter(&game_id) {
-        ctx.db.unit().id().delete(unit.id);
+        remove_unit(ctx, unit.id);
     }
     for terrain in ctx.db.terrain().game_id().filter(&game_id) {
-        ctx.db.terrain().id().delete(terrain.id);
+        remove_terrain(ctx, terrain.id);
     }
+    refresh_visibility(ctx, game_id);
 }
 
 #[spacetimedb::reducer]
@@ -567,17 +1028,30 @@ pub fn roll_dice(ctx: &ReducerContext, game_id: u64) {
         game_id,
         timestamp: ctx.timestamp,
         action_type: "DICE_ROLL".to_string(),
-        description: description,
-        game_state: Some(GameState {
-            terrains: ctx.db.terrain().game_id().filter(&game_id).collect(),
-            units: ctx.db.unit().game_id().filter(&game_id).collect(),
-            underlays: ctx.db.underlay().game_id().filter(&game_id).collect(),
-            overlays: ctx.db.overlay().game_id().filter(&game_id).collect(),
-            game_id,
-        }),
+        description,
+        game_state: Some(public_game_state(ctx, game_id)),
     });
 }
 
+/// Snapshot of the pieces every player may see. The action log is public, so
+/// hidden pieces and, under fog of war, faction units are left out.
+fn public_game_state(ctx: &ReducerContext, game_id: u64) -> GameState {
+    let fog_of_war = ctx.db.games().id().find(game_id).is_some_and(|game| game.fog_of_war);
+    GameState {
+        terrains: ctx.db.terrain().game_id().filter(&game_id).filter(|t| t.visibility == Visibility::Everyone).collect(),
+        units: ctx
+            .db
+            .unit()
+            .game_id()
+            .filter(&game_id)
+            .filter(|u| u.visibility == Visibility::Everyone && !(fog_of_war && u.faction_id.is_some()))
+            .collect(),
+        underlays: ctx.db.underlay().game_id().filter(&game_id).filter(|u| u.visibility == Visibility::Everyone).collect(),
+        overlays: ctx.db.overlay().game_id().filter(&game_id).filter(|o| o.visibility == Visibility::Everyone).collect(),
+        game_id,
+    }
+}
+
 #[spacetimedb::reducer]
 pub fn chat_message(ctx: &ReducerContext, game_id: u64, message: String) {
     ctx.db.action().insert(Action {
@@ -591,33 +1065,67 @@ pub fn chat_message(ctx: &ReducerContext, game_id: u64, message: String) {
 }
 
 #[spacetimedb::reducer]
-pub fn add_underlay(ctx: &ReducerContext, game_id: u64, shape_type: ShapeType, size: Vec<u32>, color: String, position: Vec<Position>) {
-    ctx.db.underlay().insert(Underlay { 
+pub fn add_underlay(
+    ctx: &ReducerContext,
+    game_id: u64,
+    shape_type: ShapeType,
+    size: Vec<u32>,
+    color: String,
+    position: Vec<Position>,
+    visibility: Option<Visibility>,
+) -> Result<(), String> {
+    validate_piece(&shape_type, &position, &size, 0.0, &color)?;
+    let visibility = placement_visibility(ctx, game_id, visibility)?;
+    insert_underlay_row(ctx, Underlay { 
         id: 0, 
         game_id,
         shape_type, 
         size, 
         color, 
         position,
+        rotation: 0.0,
+        z_order: 0,
+        owner: Some(ctx.sender),
+        visibility,
     });
+    Ok(())
 }
 
 #[spacetimedb::reducer]
-pub fn add_overlay(ctx: &ReducerContext, game_id: u64, shape_type: ShapeType, size: Vec<u32>, color: String, position: Vec<Position>) {
-    ctx.db.overlay().insert(Overlay { 
+#[allow(clippy::too_many_arguments)]
+pub fn add_overlay(
+    ctx: &ReducerContext,
+    game_id: u64,
+    shape_type: ShapeType,
+    size: Vec<u32>,
+    color: String,
+    position: Vec<Position>,
+    text: Option<TextContent>,
+    visibility: Option<Visibility>,
+) -> Result<(), String> {
+    validate_piece(&shape_type, &position, &size, 0.0, &color)?;
+    validate_label(&shape_type, &size, text.as_ref())?;
+    let visibility = placement_visibility(ctx, game_id, visibility)?;
+    insert_overlay_row(ctx, Overlay { 
         id: 0, 
         game_id,
         shape_type, 
         size, 
         color, 
         position,
+        rotation: 0.0,
+        z_order: 0,
+        owner: Some(ctx.sender),
+        visibility,
+        text,
     });
+    Ok(())
 }
 
 #[spacetimedb::reducer]
 pub fn delete_underlay(ctx: &ReducerContext, underlay_id: u64) {
-    if let Some(_underlay) = ctx.db.underlay().id().find(underlay_id) {
-        ctx.db.underlay().id().delete(underlay_id);
+    if let Some(_underlay) = ctx.db.underlay().id().find(underlay_id).filter(|u| sender_sees_piece(ctx, u.game_id, u.owner, u.visibility)) {
+        remove_underlay(ctx, underlay_id);
     } else {
         log::error!("Failed to delete underlay: ID {} not found", underlay_id);
     }
@@ -625,85 +1133,2610 @@ pub fn delete_underlay(ctx: &ReducerContext, underlay_id: u64) {
 
 #[spacetimedb::reducer]
 pub fn delete_overlay(ctx: &ReducerContext, overlay_id: u64) {
-    if let Some(_overlay) = ctx.db.overlay().id().find(overlay_id) {
-        ctx.db.overlay().id().delete(overlay_id);
+    if let Some(_overlay) = ctx.db.overlay().id().find(overlay_id).filter(|o| sender_sees_piece(ctx, o.game_id, o.owner, o.visibility)) {
+        remove_overlay(ctx, overlay_id);
     } else {
         log::error!("Failed to delete overlay: ID {} not found", overlay_id);
     }
 }
 
+/// Changes to a piece. Fields left as `None` keep their current value.
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct PieceEdit {
+    pub shape_type: Option<ShapeType>,
+    pub position: Option<Vec<Position>>,
+    pub size: Option<Vec<u32>>,
+    pub color: Option<String>,
+    pub rotation: Option<f32>,
+    pub z_order: Option<i32>,
+}
+
+impl PieceEdit {
+    /// Writes the edit into a piece's fields, then checks the result the way
+    /// a newly added piece is checked. Returns whether the outline changed.
+    fn apply(
+        self,
+        shape_type: &mut ShapeType,
+        position: &mut Vec<Position>,
+        size: &mut Vec<u32>,
+        color: &mut String,
+        rotation: &mut f32,
+        z_order: &mut i32,
+    ) -> Result<bool, String> {
+        let reshaped = self.shape_type.is_some() || self.position.is_some() || self.size.is_some() || self.rotation.is_some();
+        *shape_type = self.shape_type.unwrap_or(*shape_type);
+        *position = self.position.unwrap_or(std::mem::take(position));
+        *size = self.size.unwrap_or(std::mem::take(size));
+        *color = self.color.unwrap_or(std::mem::take(color));
+        *rotation = self.rotation.map_or(*rotation, |r| r.rem_euclid(360.0));
+        *z_order = self.z_order.unwrap_or(*z_order);
+        validate_piece(shape_type, position, size, *rotation, color)?;
+        Ok(reshaped)
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn update_unit(ctx: &ReducerContext, unit_id: u64, edit: PieceEdit) -> Result<(), String> {
+    let mut unit = ctx
+        .db
+        .unit()
+        .id()
+        .find(unit_id)
+        .filter(|unit| sender_sees_unit(ctx, unit))
+        .ok_or(format!("Unit {} not found", unit_id))?;
+    check_unit_control(ctx, unit_id)?;
+    if unit.faction_id.is_some() && edit.color.is_some() {
+        return Err(format!("Unit {} takes its color from its faction", unit_id));
+    }
+    if edit.position.is_some() || edit.rotation.is_some() {
+        if let Some(kind) = immobilizing_condition(ctx, unit_id) {
+            return Err(format!("Unit {} is {:?} and cannot be moved", unit_id, kind));
+        }
+    }
+    let reshaped = edit.apply(&mut unit.shape_type, &mut unit.position, &mut unit.size, &mut unit.color, &mut unit.rotation, &mut unit.z_order)?;
+    validate_label(&unit.shape_type, &unit.size, unit.text.as_ref())?;
+    if reshaped && unit_blocked(ctx, &unit) {
+        return Err("Unit would overlap another unit or impassable terrain".to_string());
+    }
+    if reshaped {
+        check_squad_coherency(ctx, &unit)?;
+    }
+    let game_id = unit.game_id;
+    update_unit_row(ctx, unit);
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_terrain(ctx: &ReducerContext, terrain_id: u64, edit: PieceEdit, traversable: Option<bool>) -> Result<(), String> {
+    let mut terrain = ctx
+        .db
+        .terrain()
+        .id()
+        .find(terrain_id)
+        .filter(|t| sender_sees_piece(ctx, t.game_id, t.owner, t.visibility))
+        .ok_or(format!("Terrain {} not found", terrain_id))?;
+    let reshaped = edit.apply(
+        &mut terrain.shape_type,
+        &mut terrain.position,
+        &mut terrain.size,
+        &mut terrain.color,
+        &mut terrain.rotation,
+        &mut terrain.z_order,
+    )?;
+    let blocks_more = traversable.is_some_and(|traversable| terrain.traversable && !traversable);
+    terrain.traversable = traversable.unwrap_or(terrain.traversable);
+    if (reshaped || blocks_more) && terrain_blocks_units(ctx, &terrain) {
+        return Err("Impassable terrain would overlap a unit".to_string());
+    }
+    let game_id = terrain.game_id;
+    update_terrain_row(ctx, terrain);
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_underlay(ctx: &ReducerContext, underlay_id: u64, edit: PieceEdit) -> Result<(), String> {
+    let mut underlay = ctx
+        .db
+        .underlay()
+        .id()
+        .find(underlay_id)
+        .filter(|u| sender_sees_piece(ctx, u.game_id, u.owner, u.visibility))
+        .ok_or(format!("Underlay {} not found", underlay_id))?;
+    edit.apply(
+        &mut underlay.shape_type,
+        &mut underlay.position,
+        &mut underlay.size,
+        &mut underlay.color,
+        &mut underlay.rotation,
+        &mut underlay.z_order,
+    )?;
+    update_underlay_row(ctx, underlay);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn update_overlay(ctx: &ReducerContext, overlay_id: u64, edit: PieceEdit) -> Result<(), String> {
+    let mut overlay = ctx
+        .db
+        .overlay()
+        .id()
+        .find(overlay_id)
+        .filter(|o| sender_sees_piece(ctx, o.game_id, o.owner, o.visibility))
+        .ok_or(format!("Overlay {} not found", overlay_id))?;
+    edit.apply(
+        &mut overlay.shape_type,
+        &mut overlay.position,
+        &mut overlay.size,
+        &mut overlay.color,
+        &mut overlay.rotation,
+        &mut overlay.z_order,
+    )?;
+    validate_label(&overlay.shape_type, &overlay.size, overlay.text.as_ref())?;
+    update_overlay_row(ctx, overlay);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_unit_text(ctx: &ReducerContext, unit_id: u64, text: Option<TextContent>) -> Result<(), String> {
+    let unit = ctx
+        .db
+        .unit()
+        .id()
+        .find(unit_id)
+        .filter(|unit| sender_sees_unit(ctx, unit))
+        .ok_or(format!("Unit {} not found", unit_id))?;
+    check_unit_control(ctx, unit_id)?;
+    let size = label_size(&unit.shape_type, unit.size.clone(), text.as_ref());
+    validate_label(&unit.shape_type, &size, text.as_ref())?;
+    update_unit_row(ctx, Unit { size, text, ..unit });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_overlay_text(ctx: &ReducerContext, overlay_id: u64, text: Option<TextContent>) -> Result<(), String> {
+    let overlay = ctx
+        .db
+        .overlay()
+        .id()
+        .find(overlay_id)
+        .filter(|o| sender_sees_piece(ctx, o.game_id, o.owner, o.visibility))
+        .ok_or(format!("Overlay {} not found", overlay_id))?;
+    let size = label_size(&overlay.shape_type, overlay.size.clone(), text.as_ref());
+    validate_label(&overlay.shape_type, &size, text.as_ref())?;
+    update_overlay_row(ctx, Overlay { size, text, ..overlay });
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn handle_mouse_event(ctx: &ReducerContext, game_id: u64, event_type: String, x: u32, y: u32, offset_x: u32, offset_y: u32) {
     match event_type.as_str() {
         "mousedown" => {
-            let units: Vec<Unit> = ctx.db.unit().game_id().filter(&game_id).collect();
-            if let Some(unit_id) = find_item_at_point(&units, x, y) {
+            if let Some((_, unit_id)) = pick(ctx, game_id, x, y, &[Layer::Unit]) {
+                if let Err(error) = check_unit_control(ctx, unit_id) {
+                    log::info!("{}", error);
+                    return;
+                }
+                if let Some(kind) = immobilizing_condition(ctx, unit_id) {
+                    log::info!("Unit {} is {:?} and cannot be moved", unit_id, kind);
+                    return;
+                }
+                if let Some(selected) = ctx.db.selected_unit().id().find(unit_id) {
+                    if selected.selector != ctx.sender {
+                        log::info!("Unit {} is already being moved", unit_id);
+                        return;
+                    }
+                    ctx.db.selected_unit().id().delete(unit_id);
+                }
+                let Some(unit) = ctx.db.unit().id().find(unit_id) else {
+                    return;
+                };
                 ctx.db.selected_unit().insert(SelectedUnit { 
                     id: unit_id,
                     game_id,
+                    selector: ctx.sender,
                     start_x: x,
                     start_y: y,
                     offset_x: 0,
                     offset_y: 0,
+                    moved: false,
+                    start_position: unit.position,
                 });
             }
         }
         "mousemove" => {
-            if let Some(selected) = ctx.db.selected_unit().game_id().filter(&game_id).next() {
+            if let Some(selected) = ctx.db.selected_unit().selector().filter(&ctx.sender).find(|selected| selected.game_id == game_id) {
                 if let Some(unit) = ctx.db.unit().id().find(selected.id) {
-                    let new_x = unit.position[0].x + offset_x;
-                    let new_y = unit.position[0].y + offset_y;
-                    
-                    let new_pos = vec![Position { x: new_x, y: new_y }];
-                    let units: Vec<Unit> = ctx.db.unit().game_id().filter(&game_id).collect();
-                    let terrains: Vec<Terrain> = ctx.db.terrain().game_id().filter(&game_id).collect();
-                    let will_collide = check_shape_collision_items(
-                        &unit.shape_type,
-                        &new_pos,
-                        &unit.size,
-                        &units,
-                        Some(unit.id),
-                    );
-
-                    if !will_collide {
-                        let will_collide_terrains = check_shape_collision_items(
-                            &unit.shape_type,
-                            &new_pos,
-                            &unit.size,
-                            &terrains,
-                            None,
-                        );
-                        if !will_collide_terrains {
-                            ctx.db.unit().id().update(Unit { 
-                        id: unit.id,
-                        game_id,
-                        shape_type: unit.shape_type,
-                        size: unit.size,
-                        color: unit.color,
-                        position: new_pos,
-                    });
+                    // The client sends negative offsets as wrapped u32 values
+                    let (dx, dy) = (offset_x as i32, offset_y as i32);
+                    if dx.unsigned_abs() > BOARD_WIDTH || dy.unsigned_abs() > BOARD_HEIGHT {
+                        log::error!("Failed to move unit {}: offset ({}, {}) is larger than the board", unit.id, dx, dy);
+                        return;
+                    }
+                    let area = swept_aabb(&unit, dx, dy).loosened(1.0);
+                    let units = nearby_units(ctx, game_id, &area);
+                    let terrains = nearby_terrains(ctx, game_id, &area);
+                    let unit_world = CollisionWorld::new(&units, true, Some(unit.id));
+                    let terrain_world = CollisionWorld::new(&terrains, true, None);
+
+                    // Stop at the first contact along the drag instead of jumping past it
+                    if let Some(new_pos) = sweep_piece(&unit, dx, dy, &[&unit_world, &terrain_world]) {
+                        let moved = Unit { position: new_pos, ..unit };
+                        if let Err(error) = check_squad_coherency(ctx, &moved) {
+                            log::info!("{}", error);
+                            return;
+                        }
+                        update_unit_row(ctx, moved);
+                        if !selected.moved {
+                            ctx.db.selected_unit().id().update(SelectedUnit { moved: true, ..selected });
                         }
                     }
                 }
             }
         }
         "mouseup" => {
-            for selected in ctx.db.selected_unit().game_id().filter(&game_id) {
+            for selected in ctx.db.selected_unit().selector().filter(&ctx.sender).filter(|selected| selected.game_id == game_id) {
                 ctx.db.selected_unit().id().delete(selected.id);
+                if selected.moved {
+                    log_charges(ctx, selected.id, selected.start_position);
+                    refresh_visibility(ctx, game_id);
+                }
             }
         }
         _ => {}
     }
 }
 
-#[spacetimedb::table(name = selected_unit, public)]
-pub struct SelectedUnit {
-    #[primary_key]
-    id: u64,
-    #[index(btree)]
-    game_id: u64,
-    start_x: u32,
-    start_y: u32,
-    offset_x: u32,
-    offset_y: u32,
+/// The units in base contact with a unit, leaving out any the sender can't
+/// see so the log doesn't give them away.
+fn units_in_contact(ctx: &ReducerContext, unit: &Unit) -> Vec<Unit> {
+    let Some(shape) = create_shape_obj(&unit.shape_type, &unit.position, &unit.size) else {
+        return Vec::new();
+    };
+    let iso = shape_isometry(&unit.shape_type, &unit.position, unit.rotation);
+    let units: Vec<Unit> = nearby_units(ctx, unit.game_id, &swept_aabb(unit, 0, 0).loosened(CONTACT_DISTANCE))
+        .into_iter()
+        .filter(|target| sender_sees_unit(ctx, target))
+        .collect();
+    let touching = CollisionWorld::new(&units, false, Some(unit.id)).items_within(&*shape, &iso, CONTACT_DISTANCE);
+    units.into_iter().filter(|target| touching.contains(&target.id)).collect()
+}
+
+/// Logs a charge against every enemy unit the dragged unit ended up in base
+/// contact with, including which of the target's arcs it came in from. Units
+/// it was already touching where the drag started and units of its own
+/// faction are not charged.
+fn log_charges(ctx: &ReducerContext, unit_id: u64, start_position: Vec<Position>) {
+    let Some(unit) = ctx.db.unit().id().find(unit_id) else {
+        return;
+    };
+    let at_start = Unit { position: start_position, ..unit.clone() };
+    let already_touching: Vec<u64> = units_in_contact(ctx, &at_start).iter().map(|target| target.id).collect();
+    let charger_center = shape_center(&unit.shape_type, &unit.position);
+    for target in units_in_contact(ctx, &unit) {
+        if already_touching.contains(&target.id) || (unit.faction_id.is_some() && target.faction_id == unit.faction_id) {
+            continue;
+        }
+        let (front, rear) = unit_arc_widths(ctx, target.id);
+        let arc = facing_arc(&target, front, rear, charger_center);
+        log_action(ctx, unit.game_id, "CHARGE", format!("⚔ Unit {} charged unit {} in the {:?} arc", unit_id, target.id, arc));
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn rotate_unit(ctx: &ReducerContext, unit_id: u64, rotation: f32) {
+    let Some(unit) = ctx.db.unit().id().find(unit_id).filter(|unit| sender_sees_unit(ctx, unit)) else {
+        log::error!("Failed to rotate unit: ID {} not found", unit_id);
+        return;
+    };
+    if let Err(error) = check_unit_control(ctx, unit_id) {
+        log::error!("Failed to rotate unit: {}", error);
+        return;
+    }
+    if let Some(kind) = immobilizing_condition(ctx, unit_id) {
+        log::error!("Failed to rotate unit {}: it is {:?}", unit_id, kind);
+        return;
+    }
+    if !rotation.is_finite() {
+        log::error!("Failed to rotate unit {}: {} is not a number of degrees", unit_id, rotation);
+        return;
+    }
+    let rotated = Unit { rotation: rotation.rem_euclid(360.0), ..unit };
+    if unit_blocked(ctx, &rotated) {
+        log::error!("Failed to rotate unit {}: it would overlap another piece", unit_id);
+        return;
+    }
+    if let Err(error) = check_squad_coherency(ctx, &rotated) {
+        log::error!("Failed to rotate unit: {}", error);
+        return;
+    }
+    let game_id = rotated.game_id;
+    update_unit_row(ctx, rotated);
+    refresh_visibility(ctx, game_id);
+}
+
+#[spacetimedb::reducer]
+pub fn set_unit_arcs(ctx: &ReducerContext, unit_id: u64, front: f32, rear: f32) {
+    let Some(unit) = ctx.db.unit().id().find(unit_id).filter(|unit| sender_sees_unit(ctx, unit)) else {
+        log::error!("Failed to set arcs: unit ID {} not found", unit_id);
+        return;
+    };
+    if let Err(error) = check_unit_control(ctx, unit_id) {
+        log::error!("Failed to set arcs: {}", error);
+        return;
+    }
+    if !front.is_finite() || !rear.is_finite() || front < 0.0 || rear < 0.0 || front + rear > 360.0 {
+        log::error!("Failed to set arcs for unit {}: front {} and rear {} must be positive and fit in 360 degrees", unit_id, front, rear);
+        return;
+    }
+    ctx.db.unit_arcs().unit_id().delete(unit_id);
+    ctx.db.unit_arcs().insert(UnitArcs {
+        unit_id,
+        game_id: unit.game_id,
+        front,
+        rear,
+    });
+}
+
+#[spacetimedb::reducer]
+pub fn check_facing_arc(ctx: &ReducerContext, unit_id: u64, target: FacingTarget) {
+    let Some(unit) = ctx.db.unit().id().find(unit_id).filter(|unit| sender_sees_unit(ctx, unit)) else {
+        log::error!("Failed to check facing: unit ID {} not found", unit_id);
+        return;
+    };
+    let (point, target_name) = match &target {
+        FacingTarget::Point(position) => (
+            Point::new(position.x as f32, position.y as f32),
+            format!("Point ({}, {})", position.x, position.y),
+        ),
+        FacingTarget::Unit(target_id) => match ctx.db.unit().id().find(*target_id).filter(|other| sender_sees_unit(ctx, other)) {
+            Some(other) => (shape_center(&other.shape_type, &other.position), format!("Unit {}", target_id)),
+            None => {
+                log::error!("Failed to check facing: target unit ID {} not found", target_id);
+                return;
+            }
+        },
+    };
+    let (front, rear) = unit_arc_widths(ctx, unit_id);
+    let arc = facing_arc(&unit, front, rear, point);
+    log_action(ctx, unit.game_id, "FACING", format!("{} is in the {:?} arc of unit {}", target_name, arc, unit_id));
+}
+
+#[spacetimedb::reducer]
+pub fn request_path(ctx: &ReducerContext, unit_id: u64, target: Position) {
+    let Some(unit) = ctx.db.unit().id().find(unit_id).filter(|unit| sender_sees_unit(ctx, unit)) else {
+        log::error!("Failed to plan path: unit ID {} not found", unit_id);
+        return;
+    };
+    // Routing around units the sender can't see would give them away; the
+    // path is swept again against every unit when it is committed.
+    let units: Vec<Unit> = ctx
+        .db
+        .unit()
+        .game_id()
+        .filter(&unit.game_id)
+        .filter(|other| sender_sees_unit(ctx, other))
+        .collect();
+    let terrains: Vec<Terrain> = ctx.db.terrain().game_id().filter(&unit.game_id).collect();
+    let unit_world = CollisionWorld::new(&units, true, Some(unit.id));
+    let terrain_world = CollisionWorld::new(&terrains, true, None);
+
+    ctx.db.planned_path().unit_id().delete(unit_id);
+    match find_path(&unit, &target, &[&unit_world, &terrain_world], (BOARD_WIDTH, BOARD_HEIGHT)) {
+        Some(waypoints) => {
+            ctx.db.planned_path().insert(PlannedPath {
+                unit_id,
+                game_id: unit.game_id,
+                waypoints,
+            });
+        }
+        None => log::error!("No path found for unit {} to ({}, {})", unit_id, target.x, target.y),
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn commit_path(ctx: &ReducerContext, unit_id: u64) {
+    let Some(path) = ctx.db.planned_path().unit_id().find(unit_id) else {
+        log::error!("Failed to commit path: no planned path for unit {}", unit_id);
+        return;
+    };
+    ctx.db.planned_path().unit_id().delete(unit_id);
+    let Some(mut unit) = ctx.db.unit().id().find(unit_id) else {
+        log::error!("Failed to commit path: unit ID {} not found", unit_id);
+        return;
+    };
+    if let Some(kind) = immobilizing_condition(ctx, unit_id) {
+        log::error!("Failed to commit path: unit {} is {:?}", unit_id, kind);
+        return;
+    }
+    if let Err(error) = check_unit_control(ctx, unit_id) {
+        log::error!("Failed to commit path: {}", error);
+        return;
+    }
+    let mut area = swept_aabb(&unit, 0, 0);
+    for waypoint in &path.waypoints {
+        let dx = waypoint.x as i32 - unit.position[0].x as i32;
+        let dy = waypoint.y as i32 - unit.position[0].y as i32;
+        area.merge(&swept_aabb(&unit, dx, dy));
+    }
+    let units = nearby_units(ctx, unit.game_id, &area);
+    let terrains = nearby_terrains(ctx, unit.game_id, &area);
+    let unit_world = CollisionWorld::new(&units, true, Some(unit.id));
+    let terrain_world = CollisionWorld::new(&terrains, true, None);
+
+    // The board may have changed since the path was planned, so every leg is
+    // swept again and the unit stops at the first contact.
+    for waypoint in &path.waypoints {
+        let dx = waypoint.x as i32 - unit.position[0].x as i32;
+        let dy = waypoint.y as i32 - unit.position[0].y as i32;
+        match sweep_piece(&unit, dx, dy, &[&unit_world, &terrain_world]) {
+            Some(new_pos) => {
+                let reached = new_pos[0].x == waypoint.x && new_pos[0].y == waypoint.y;
+                unit.position = new_pos;
+                if !reached {
+                    break;
+                }
+            }
+            None => break,
+        }
+    }
+    if let Err(error) = check_squad_coherency(ctx, &unit) {
+        log::error!("Failed to commit path: {}", error);
+        return;
+    }
+    let game_id = unit.game_id;
+    update_unit_row(ctx, unit);
+    refresh_visibility(ctx, game_id);
+}
+
+#[spacetimedb::reducer]
+pub fn check_line_of_sight(ctx: &ReducerContext, from_unit_id: u64, to_unit_id: u64, log_result: bool) {
+    let find = |unit_id| ctx.db.unit().id().find(unit_id).filter(|unit| sender_sees_unit(ctx, unit));
+    let (Some(from), Some(to)) = (find(from_unit_id), find(to_unit_id)) else {
+        log::error!("Failed to check line of sight: unit ID {} or {} not found", from_unit_id, to_unit_id);
+        return;
+    };
+    let game_id = from.game_id;
+    let area = swept_aabb(&from, 0, 0).merged(&swept_aabb(&to, 0, 0));
+    let others: Vec<Unit> = nearby_units(ctx, game_id, &area)
+        .into_iter()
+        .filter(|u| u.id != from.id && u.id != to.id)
+        .collect();
+    let terrains = nearby_terrains(ctx, game_id, &area);
+    let status = line_of_sight(
+        &from,
+        &to,
+        &CollisionWorld::new(&others, false, None),
+        &CollisionWorld::new(&terrains, true, None),
+        &CollisionWorld::new(&terrains, false, None),
+    );
+
+    for old in ctx.db.los_result().requester().filter(&ctx.sender).filter(|r| r.game_id == game_id) {
+        ctx.db.los_result().id().delete(old.id);
+    }
+    ctx.db.los_result().insert(LosResult {
+        id: 0,
+        game_id,
+        requester: ctx.sender,
+        from_unit_id,
+        to_unit_id,
+        status,
+        timestamp: ctx.timestamp,
+    });
+    if log_result {
+        log_action(
+            ctx,
+            game_id,
+            "LINE_OF_SIGHT",
+            format!("Line of sight from unit {} to unit {}: {:?}", from_unit_id, to_unit_id, status),
+        );
+    }
+}
+
+#[derive(SpacetimeType, Copy, Clone, Debug, PartialEq)]
+pub enum MeasureTarget {
+    Unit(u64),
+    Terrain(u64),
+}
+
+fn find_measure_target(ctx: &ReducerContext, target: MeasureTarget) -> Option<(u64, Box<dyn Collidable>)> {
+    match target {
+        MeasureTarget::Unit(id) => ctx
+            .db
+            .unit()
+            .id()
+            .find(id)
+            .filter(|u| sender_sees_unit(ctx, u))
+            .map(|u| (u.game_id, Box::new(u) as Box<dyn Collidable>)),
+        MeasureTarget::Terrain(id) => ctx
+            .db
+            .terrain()
+            .id()
+            .find(id)
+            .filter(|t| sender_sees_piece(ctx, t.game_id, t.owner, t.visibility))
+            .map(|t| (t.game_id, Box::new(t) as Box<dyn Collidable>)),
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn measure(ctx: &ReducerContext, from: MeasureTarget, to: MeasureTarget, log_result: bool) {
+    let (Some((game_id, a)), Some((to_game_id, b))) = (find_measure_target(ctx, from), find_measure_target(ctx, to)) else {
+        log::error!("Failed to measure: {:?} or {:?} not found", from, to);
+        return;
+    };
+    if game_id != to_game_id {
+        log::error!("Failed to measure: {:?} and {:?} are in different games", from, to);
+        return;
+    }
+    let Some((edge_distance, center_distance)) = measure_items(a.as_ref(), b.as_ref()) else {
+        log::error!("Failed to measure: {:?} or {:?} has no shape", from, to);
+        return;
+    };
+
+    for old in ctx.db.measurement().requester().filter(&ctx.sender).filter(|m| m.game_id == game_id) {
+        ctx.db.measurement().id().delete(old.id);
+    }
+    ctx.db.measurement().insert(Measurement {
+        id: 0,
+        game_id,
+        requester: ctx.sender,
+        from,
+        to,
+        edge_distance,
+        center_distance,
+        timestamp: ctx.timestamp,
+    });
+    if log_result {
+        log_action(
+            ctx,
+            game_id,
+            "MEASURE",
+            format!("📏 {:?} to {:?}: {:.1} edge to edge, {:.1} center to center", from, to, edge_distance, center_distance),
+        );
+    }
+}
+
+/// The latest measurement each player asked for in a game; private, and read
+/// through the `my_measurements` view.
+#[spacetimedb::table(name = measurement)]
+pub struct Measurement {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    game_id: u64,
+    #[index(btree)]
+    requester: Identity,
+    from: MeasureTarget,
+    to: MeasureTarget,
+    edge_distance: f32,
+    center_distance: f32,
+    timestamp: Timestamp,
+}
+
+#[spacetimedb::view(name = my_measurements, public)]
+fn my_measurements(ctx: &ViewContext) -> Vec<Measurement> {
+    ctx.db.measurement().requester().filter(&ctx.sender).collect()
+}
+
+#[spacetimedb::reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn add_area_template(
+    ctx: &ReducerContext,
+    game_id: u64,
+    kind: TemplateKind,
+    origin: Position,
+    size: u32,
+    width: u32,
+    direction: f32,
+    color: String,
+    respect_line_of_sight: bool,
+) -> Result<(), String> {
+    if origin.x > BOARD_WIDTH || origin.y > BOARD_HEIGHT {
+        return Err(format!("Position ({}, {}) is outside the {}x{} board", origin.x, origin.y, BOARD_WIDTH, BOARD_HEIGHT));
+    }
+    if size == 0 || size > MAX_SHAPE_SIZE || width > MAX_SHAPE_SIZE {
+        return Err(format!("Template size must be between 1 and {}", MAX_SHAPE_SIZE));
+    }
+    if matches!(kind, TemplateKind::Cone | TemplateKind::Line) && width == 0 {
+        return Err(format!("{:?} templates need a width of at least 1", kind));
+    }
+    if !direction.is_finite() {
+        return Err(format!("Direction {} is not a number of degrees", direction));
+    }
+    validate_color(&color)?;
+    ctx.db.area_template().insert(AreaTemplate {
+        id: 0,
+        game_id,
+        kind,
+        origin,
+        size,
+        width,
+        direction,
+        color,
+        respect_line_of_sight,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn delete_area_template(ctx: &ReducerContext, template_id: u64) {
+    if let Some(_template) = ctx.db.area_template().id().find(template_id) {
+        ctx.db.area_template().id().delete(template_id);
+    } else {
+        log::error!("Failed to delete area template: ID {} not found", template_id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn apply_area_template(ctx: &ReducerContext, template_id: u64) {
+    let Some(template) = ctx.db.area_template().id().find(template_id) else {
+        log::error!("Failed to apply area template: ID {} not found", template_id);
+        return;
+    };
+    let Some((shape, iso)) = create_template_shape(&template) else {
+        log::error!("Failed to apply area template {}: it has no area", template_id);
+        return;
+    };
+    let template_area = shape.compute_aabb(&iso);
+    // Units the sender can't see are left out so the log doesn't give them away
+    let units: Vec<Unit> = nearby_units(ctx, template.game_id, &template_area)
+        .into_iter()
+        .filter(|unit| sender_sees_unit(ctx, unit))
+        .collect();
+    // Sight lines run from the origin to points on each unit, which may stick
+    // out past the template itself.
+    let terrain_area = units
+        .iter()
+        .fold(template_area, |area, unit| area.merged(&swept_aabb(unit, 0, 0)));
+    let terrains = nearby_terrains(ctx, template.game_id, &terrain_area);
+    let blocking_terrain_world = CollisionWorld::new(&terrains, true, None);
+    let origin = Point::new(template.origin.x as f32, template.origin.y as f32);
+
+    let mut hit = Vec::new();
+    let mut covered = Vec::new();
+    for unit in units {
+        let Some(fully_covered) = template_coverage(&template, &unit) else {
+            continue;
+        };
+        if template.respect_line_of_sight
+            && sight_points(&unit.shape_type, &unit.position, &unit.size, unit.rotation, origin)
+                .into_iter()
+                .all(|point| blocking_terrain_world.segment_hits(origin, point))
+        {
+            continue;
+        }
+        hit.push(unit.id.to_string());
+        if fully_covered {
+            covered.push(unit.id.to_string());
+        }
+    }
+
+    let description = if hit.is_empty() {
+        format!("💥 {:?} template {} hits no units", template.kind, template_id)
+    } else {
+        format!(
+            "💥 {:?} template {} hits units {} (fully covered: {})",
+            template.kind,
+            template_id,
+            hit.join(", "),
+            if covered.is_empty() { "none".to_string() } else { covered.join(", ") },
+        )
+    };
+    log_action(ctx, template.game_id, "AREA_TEMPLATE", description);
+}
+
+/// Longest attribute name, in characters.
+const MAX_ATTRIBUTE_NAME_LENGTH: usize = 32;
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum AttributeValue {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+}
+
+impl std::fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttributeValue::Int(value) => write!(f, "{}", value),
+            AttributeValue::Float(value) => write!(f, "{}", value),
+            AttributeValue::Str(value) => write!(f, "\"{}\"", value),
+            AttributeValue::Bool(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl AttributeValue {
+    fn as_number(&self) -> Option<f64> {
+        match self {
+            AttributeValue::Int(value) => Some(*value as f64),
+            AttributeValue::Float(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+/// Checks a new attribute value and returns its name with surrounding
+/// whitespace removed.
+fn validate_attribute(name: &str, value: &AttributeValue, max: Option<f64>) -> Result<String, String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_ATTRIBUTE_NAME_LENGTH {
+        return Err(format!("Attribute names must be 1 to {} characters long", MAX_ATTRIBUTE_NAME_LENGTH));
+    }
+    if let AttributeValue::Float(number) = value {
+        if !number.is_finite() {
+            return Err(format!("{} is not a valid number", number));
+        }
+    }
+    if let Some(max) = max {
+        let Some(number) = value.as_number() else {
+            return Err(format!("Only numbers can have a maximum, {} is not one", value));
+        };
+        if number > max {
+            return Err(format!("{} is above the maximum of {}", value, max));
+        }
+    }
+    Ok(name)
+}
+
+fn find_attribute(ctx: &ReducerContext, unit_id: u64, name: &str) -> Option<UnitAttribute> {
+    ctx.db.unit_attribute().unit_id().filter(&unit_id).find(|attribute| attribute.name == name)
+}
+
+/// Creates or replaces a unit's attribute. A maximum may only be given for
+/// numbers, and the value must not exceed it. Only players who control the
+/// unit may change its attributes.
+#[spacetimedb::reducer]
+pub fn set_unit_attribute(ctx: &ReducerContext, unit_id: u64, name: String, value: AttributeValue, max: Option<f64>) -> Result<(), String> {
+    let unit = find_visible_unit(ctx, unit_id)?;
+    check_unit_control(ctx, unit_id)?;
+    let name = validate_attribute(&name, &value, max)?;
+    let description = match find_attribute(ctx, unit_id, &name) {
+        Some(existing) => {
+            let description = format!("Unit {} {}: {} → {}", unit_id, name, existing.value, value);
+            ctx.db.unit_attribute().id().update(UnitAttribute { value, max, ..existing });
+            description
+        }
+        None => {
+            let description = format!("Unit {} {}: set to {}", unit_id, name, value);
+            ctx.db.unit_attribute().insert(UnitAttribute {
+                id: 0,
+                unit_id,
+                game_id: unit.game_id,
+                name,
+                value,
+                max,
+            });
+            description
+        }
+    };
+    log_action(ctx, unit.game_id, "ATTRIBUTE", description);
+    Ok(())
+}
+
+/// Adds `amount` to a numeric attribute, stopping at its maximum. Integer
+/// attributes only take whole amounts.
+fn change_unit_attribute(ctx: &ReducerContext, unit_id: u64, name: &str, amount: f64) -> Result<(), String> {
+    find_visible_unit(ctx, unit_id)?;
+    check_unit_control(ctx, unit_id)?;
+    let attribute = find_attribute(ctx, unit_id, name).ok_or(format!("Unit {} has no attribute {}", unit_id, name))?;
+    if !amount.is_finite() {
+        return Err(format!("{} is not a valid amount", amount));
+    }
+    let capped = |number: f64| attribute.max.map_or(number, |max| number.min(max));
+    let value = match attribute.value {
+        AttributeValue::Int(current) if amount.fract() == 0.0 => {
+            AttributeValue::Int(capped(current as f64 + amount) as i64)
+        }
+        AttributeValue::Int(_) => return Err(format!("{} is an integer and cannot change by {}", name, amount)),
+        AttributeValue::Float(current) => AttributeValue::Float(capped(current + amount)),
+        _ => return Err(format!("{} is not a number", name)),
+    };
+    log_action(
+        ctx,
+        attribute.game_id,
+        "ATTRIBUTE",
+        format!("Unit {} {}: {} → {}", unit_id, name, attribute.value, value),
+    );
+    ctx.db.unit_attribute().id().update(UnitAttribute { value, ..attribute });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn increment_unit_attribute(ctx: &ReducerContext, unit_id: u64, name: String, amount: f64) -> Result<(), String> {
+    change_unit_attribute(ctx, unit_id, &name, amount)
+}
+
+#[spacetimedb::reducer]
+pub fn decrement_unit_attribute(ctx: &ReducerContext, unit_id: u64, name: String, amount: f64) -> Result<(), String> {
+    change_unit_attribute(ctx, unit_id, &name, -amount)
+}
+
+#[spacetimedb::reducer]
+pub fn delete_unit_attribute(ctx: &ReducerContext, unit_id: u64, name: String) -> Result<(), String> {
+    find_visible_unit(ctx, unit_id)?;
+    check_unit_control(ctx, unit_id)?;
+    let attribute = find_attribute(ctx, unit_id, &name).ok_or(format!("Unit {} has no attribute {}", unit_id, name))?;
+    ctx.db.unit_attribute().id().delete(attribute.id);
+    log_action(ctx, attribute.game_id, "ATTRIBUTE", format!("Unit {} {}: removed (was {})", unit_id, name, attribute.value));
+    Ok(())
+}
+
+#[derive(SpacetimeType, Copy, Clone, Debug, PartialEq)]
+pub enum ConditionKind {
+    Stunned,
+    Pinned,
+    Poisoned,
+    Hidden,
+}
+
+impl ConditionKind {
+    /// Whether applying the condition again adds a stack instead of just
+    /// refreshing its duration.
+    fn stacks(self) -> bool {
+        matches!(self, ConditionKind::Poisoned)
+    }
+
+    /// Whether the condition stops the unit from being moved.
+    fn immobilizes(self) -> bool {
+        matches!(self, ConditionKind::Stunned | ConditionKind::Pinned)
+    }
+
+    /// Whether the condition changes who can see the unit under fog of war.
+    fn affects_sight(self) -> bool {
+        matches!(self, ConditionKind::Hidden)
+    }
+}
+
+/// Returns the first condition on the unit that stops it from moving.
+fn immobilizing_condition(ctx: &ReducerContext, unit_id: u64) -> Option<ConditionKind> {
+    ctx.db
+        .condition()
+        .unit_id()
+        .filter(&unit_id)
+        .map(|condition| condition.kind)
+        .find(|kind| kind.immobilizes())
+}
+
+/// Puts a condition on a unit for `rounds` rounds, or until removed if none
+/// is given. Applying a condition the unit already has keeps the longer of
+/// the two durations, and adds a stack if the condition stacks. Only players
+/// who control the unit, or the game master, may.
+#[spacetimedb::reducer]
+pub fn apply_condition(ctx: &ReducerContext, unit_id: u64, kind: ConditionKind, rounds: Option<u32>) -> Result<(), String> {
+    let unit = find_visible_unit(ctx, unit_id)?;
+    check_unit_control(ctx, unit_id)?;
+    if rounds == Some(0) {
+        return Err("A condition must last at least one round".to_string());
+    }
+    let existing = ctx.db.condition().unit_id().filter(&unit_id).find(|condition| condition.kind == kind);
+    let description = match existing {
+        Some(existing) => {
+            let remaining_rounds = match (existing.remaining_rounds, rounds) {
+                (Some(current), Some(new)) => Some(current.max(new)),
+                _ => None,
+            };
+            let stacks = if kind.stacks() { existing.stacks + 1 } else { existing.stacks };
+            ctx.db.condition().id().update(Condition { remaining_rounds, stacks, ..existing });
+            format!("Unit {} is {:?} again ({} stacks)", unit_id, kind, stacks)
+        }
+        None => {
+            ctx.db.condition().insert(Condition {
+                id: 0,
+                unit_id,
+                game_id: unit.game_id,
+                kind,
+                remaining_rounds: rounds,
+                stacks: 1,
+            });
+            format!("Unit {} is {:?}", unit_id, kind)
+        }
+    };
+    log_action(ctx, unit.game_id, "CONDITION", description);
+    if kind.affects_sight() {
+        refresh_visibility(ctx, unit.game_id);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_condition(ctx: &ReducerContext, unit_id: u64, kind: ConditionKind) -> Result<(), String> {
+    find_visible_unit(ctx, unit_id)?;
+    check_unit_control(ctx, unit_id)?;
+    let condition = ctx
+        .db
+        .condition()
+        .unit_id()
+        .filter(&unit_id)
+        .find(|condition| condition.kind == kind)
+        .ok_or(format!("Unit {} is not {:?}", unit_id, kind))?;
+    ctx.db.condition().id().delete(condition.id);
+    log_action(ctx, condition.game_id, "CONDITION", format!("Unit {} is no longer {:?}", unit_id, kind));
+    if kind.affects_sight() {
+        refresh_visibility(ctx, condition.game_id);
+    }
+    Ok(())
+}
+
+/// Starts the next round and ticks every timed condition in the game down,
+/// removing the ones that run out. Only the game master may.
+#[spacetimedb::reducer]
+pub fn advance_round(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
+    let game = ctx.db.games().id().find(game_id).ok_or(format!("Game {} not found", game_id))?;
+    check_game_master(ctx, game_id)?;
+    let round = game.round + 1;
+    ctx.db.games().id().update(Game { round, ..game });
+    log_action(ctx, game_id, "ROUND", format!("Round {} begins", round));
+
+    let mut sight_changed = false;
+    for condition in ctx.db.condition().game_id().filter(&game_id) {
+        match condition.remaining_rounds {
+            Some(1) => {
+                ctx.db.condition().id().delete(condition.id);
+                log_action(ctx, game_id, "CONDITION", format!("Unit {} is no longer {:?}", condition.unit_id, condition.kind));
+                sight_changed |= condition.kind.affects_sight();
+            }
+            Some(remaining) => {
+                ctx.db.condition().id().update(Condition { remaining_rounds: Some(remaining - 1), ..condition });
+            }
+            None => {}
+        }
+    }
+    if sight_changed {
+        refresh_visibility(ctx, game_id);
+    }
+    Ok(())
+}
+
+/// Longest unit template or faction name, in characters.
+const MAX_NAME_LENGTH: usize = 64;
+/// Most units a single spawn may place.
+const MAX_SPAWN_COUNT: u32 = 50;
+/// Space left between neighbouring units in a formation.
+const FORMATION_GAP: u32 = 5;
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct TemplateAttribute {
+    pub name: String,
+    pub value: AttributeValue,
+    pub max: Option<f64>,
+}
+
+#[derive(SpacetimeType, Copy, Clone, Debug, PartialEq)]
+pub enum Formation {
+    /// Side by side, left to right
+    Line,
+    /// One behind the other, top to bottom
+    Column,
+    /// Rows of roughly equal length
+    Block,
+}
+
+impl Formation {
+    /// Column and row of the `index`-th slot when placing `count` units.
+    /// Slots past `count` continue the pattern, for when some are taken.
+    fn slot(self, index: u32, count: u32) -> (u32, u32) {
+        match self {
+            Formation::Line => (index, 0),
+            Formation::Column => (0, index),
+            Formation::Block => {
+                let columns = (count as f32).sqrt().ceil().max(1.0) as u32;
+                (index % columns, index / columns)
+            }
+        }
+    }
+}
+
+/// Adds a unit that has passed its placement checks, along with its starting
+/// attributes.
+fn insert_unit_with_attributes(ctx: &ReducerContext, unit: Unit, attributes: &[TemplateAttribute]) -> Unit {
+    let unit = insert_unit_row(ctx, unit);
+    for attribute in attributes {
+        ctx.db.unit_attribute().insert(UnitAttribute {
+            id: 0,
+            unit_id: unit.id,
+            game_id: unit.game_id,
+            name: attribute.name.clone(),
+            value: attribute.value.clone(),
+            max: attribute.max,
+        });
+    }
+    unit
+}
+
+#[spacetimedb::reducer]
+#[allow(clippy::too_many_arguments)]
+pub fn create_unit_template(
+    ctx: &ReducerContext,
+    game_id: Option<u64>,
+    name: String,
+    shape_type: ShapeType,
+    size: Vec<u32>,
+    color: String,
+    position: Vec<Position>,
+    text: Option<TextContent>,
+    attributes: Vec<TemplateAttribute>,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!("Template names must be 1 to {} characters long", MAX_NAME_LENGTH));
+    }
+    validate_piece(&shape_type, &position, &size, 0.0, &color)?;
+    validate_label(&shape_type, &size, text.as_ref())?;
+    let mut checked: Vec<TemplateAttribute> = Vec::new();
+    for attribute in attributes {
+        let name = validate_attribute(&attribute.name, &attribute.value, attribute.max)?;
+        if checked.iter().any(|other| other.name == name) {
+            return Err(format!("Attribute {} is listed twice", name));
+        }
+        checked.push(TemplateAttribute { name, ..attribute });
+    }
+    ctx.db.unit_template().insert(UnitTemplate {
+        id: 0,
+        game_id,
+        name,
+        shape_type,
+        size,
+        color,
+        position,
+        text,
+        attributes: checked,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn delete_unit_template(ctx: &ReducerContext, template_id: u64) {
+    if ctx.db.unit_template().id().find(template_id).is_some() {
+        ctx.db.unit_template().id().delete(template_id);
+    } else {
+        log::error!("Failed to delete unit template: ID {} not found", template_id);
+    }
+}
+
+/// Places `count` copies of a template in formation, the first one with its
+/// anchor at `position`. Slots that are off the board or already taken are
+/// skipped, and nothing is placed unless every copy fits.
+#[spacetimedb::reducer]
+pub fn spawn_from_template(
+    ctx: &ReducerContext,
+    game_id: u64,
+    template_id: u64,
+    position: Position,
+    count: u32,
+    formation: Formation,
+) -> Result<(), String> {
+    let template = ctx.db.unit_template().id().find(template_id).ok_or(format!("Unit template {} not found", template_id))?;
+    if template.game_id.is_some_and(|id| id != game_id) {
+        return Err(format!("Unit template {} belongs to another game", template_id));
+    }
+    if count == 0 || count > MAX_SPAWN_COUNT {
+        return Err(format!("Count must be between 1 and {}", MAX_SPAWN_COUNT));
+    }
+    if position.x > BOARD_WIDTH || position.y > BOARD_HEIGHT {
+        return Err(format!("Position ({}, {}) is outside the {}x{} board", position.x, position.y, BOARD_WIDTH, BOARD_HEIGHT));
+    }
+    let prototype = Unit {
+        id: 0,
+        game_id,
+        shape_type: template.shape_type,
+        size: template.size.clone(),
+        color: template.color.clone(),
+        position: template.position.clone(),
+        rotation: 0.0,
+        z_order: 0,
+        owner: Some(ctx.sender),
+        visibility: Visibility::Everyone,
+        text: template.text.clone(),
+        faction_id: None,
+        squad_id: None,
+        vision_radius: DEFAULT_VISION_RADIUS,
+    };
+    let bounds = piece_aabb(&prototype).ok_or(format!("Unit template {} has no area", template_id))?;
+    let step_x = (bounds.maxs.x - bounds.mins.x).ceil() as u32 + FORMATION_GAP;
+    let step_y = (bounds.maxs.y - bounds.mins.y).ceil() as u32 + FORMATION_GAP;
+    let anchor = &template.position[0];
+
+    let mut spawned = Vec::new();
+    for index in 0..count + MAX_SPAWN_COUNT {
+        if spawned.len() as u32 == count {
+            break;
+        }
+        let (column, row) = formation.slot(index, count);
+        let dx = (position.x + column * step_x) as i32 - anchor.x as i32;
+        let dy = (position.y + row * step_y) as i32 - anchor.y as i32;
+        if template.position.iter().any(|p| p.x as i32 + dx < 0 || p.y as i32 + dy < 0) {
+            continue;
+        }
+        let unit = Unit {
+            position: translate_positions(&template.position, dx, dy),
+            ..prototype.clone()
+        };
+        if validate_piece(&unit.shape_type, &unit.position, &unit.size, 0.0, &unit.color).is_err() || unit_blocked(ctx, &unit) {
+            continue;
+        }
+        let unit = insert_unit_with_attributes(ctx, unit, &template.attributes);
+        spawned.push(unit.id.to_string());
+    }
+    if (spawned.len() as u32) < count {
+        return Err(format!("Only {} of {} units fit in a {:?} at ({}, {})", spawned.len(), count, formation, position.x, position.y));
+    }
+
+    log_action(ctx, game_id, "SPAWN", format!("Spawned {} × {} as units {}", count, template.name, spawned.join(", ")));
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+/// Most units a single roster may deploy.
+const MAX_ROSTER_SIZE: u32 = 200;
+
+/// Creates every unit in a plain-text roster (see the `roster` module for
+/// the format) inside the deployment zone between two corners. Units are
+/// placed in rows, left to right, moving along past anything already there;
+/// nothing is created unless the whole roster fits.
+#[spacetimedb::reducer]
+pub fn import_roster(ctx: &ReducerContext, game_id: u64, roster: String, zone_min: Position, zone_max: Position) -> Result<(), String> {
+    if zone_min.x >= zone_max.x || zone_min.y >= zone_max.y || zone_max.x > BOARD_WIDTH || zone_max.y > BOARD_HEIGHT {
+        return Err(format!(
+            "({}, {}) to ({}, {}) is not a deployment zone on the {}x{} board",
+            zone_min.x, zone_min.y, zone_max.x, zone_max.y, BOARD_WIDTH, BOARD_HEIGHT
+        ));
+    }
+    let entries = parse_roster(&roster)?;
+    let total = entries.iter().map(|entry| entry.count as u64).sum::<u64>();
+    if total > MAX_ROSTER_SIZE as u64 {
+        return Err(format!("The roster has {} units, more than the maximum of {}", total, MAX_ROSTER_SIZE));
+    }
+
+    let (mut x, mut y, mut row_height) = (zone_min.x, zone_min.y, 0);
+    let mut placed = 0;
+    for entry in &entries {
+        let (width, height) = match entry.shape_type {
+            ShapeType::Circle => (entry.size[0], entry.size[0]),
+            _ => (entry.size[0], entry.size[1]),
+        };
+        if width > zone_max.x - zone_min.x || height > zone_max.y - zone_min.y {
+            return Err(format!("{} does not fit in the deployment zone", entry.name));
+        }
+        let mut attributes = entry.attributes.clone();
+        let mut label = |name: &str, value: &str| {
+            if !attributes.iter().any(|attribute| attribute.name == name) {
+                attributes.push(TemplateAttribute { name: name.to_string(), value: AttributeValue::Str(value.to_string()), max: None });
+            }
+        };
+        label("name", &entry.name);
+        let faction = match &entry.faction {
+            Some(name) => Some(roster_faction(ctx, game_id, name, &entry.color)?),
+            None => None,
+        };
+
+        for _ in 0..entry.count {
+            loop {
+                if x + width > zone_max.x {
+                    x = zone_min.x;
+                    y += row_height + FORMATION_GAP;
+                    row_height = 0;
+                }
+                if y + height > zone_max.y {
+                    return Err(format!("The deployment zone is full after {} of {} units", placed, total));
+                }
+                let unit = Unit {
+                    id: 0,
+                    game_id,
+                    shape_type: entry.shape_type,
+                    size: entry.size.clone(),
+                    color: faction.as_ref().map_or(&entry.color, |faction| &faction.color).clone(),
+                    position: vec![Position { x: x + width / 2, y: y + height / 2 }],
+                    rotation: 0.0,
+                    z_order: 0,
+                    owner: Some(ctx.sender),
+                    visibility: Visibility::Everyone,
+                    text: None,
+                    faction_id: faction.as_ref().map(|faction| faction.id),
+                    squad_id: None,
+                    vision_radius: DEFAULT_VISION_RADIUS,
+                };
+                if validate_piece(&unit.shape_type, &unit.position, &unit.size, 0.0, &unit.color).is_ok() && !unit_blocked(ctx, &unit) {
+                    insert_unit_with_attributes(ctx, unit, &attributes);
+                    placed += 1;
+                    x += width + FORMATION_GAP;
+                    row_height = row_height.max(height);
+                    break;
+                }
+                x += FORMATION_GAP;
+            }
+        }
+    }
+
+    let summary: Vec<String> = entries.iter().map(|entry| format!("{} × {}", entry.count, entry.name)).collect();
+    log_action(ctx, game_id, "ROSTER", format!("Deployed {} units: {}", placed, summary.join(", ")));
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+/// Errors unless the sender may move and edit the unit: units without a
+/// faction are open to everyone, the rest only to their faction's players
+/// and the game master.
+fn check_unit_control(ctx: &ReducerContext, unit_id: u64) -> Result<(), String> {
+    match ctx.db.unit().id().find(unit_id) {
+        Some(unit) => check_control(ctx, &unit),
+        None => Ok(()),
+    }
+}
+
+/// [`check_unit_control`] for a unit row in hand, such as an embarked one.
+fn check_control(ctx: &ReducerContext, unit: &Unit) -> Result<(), String> {
+    let Some(faction_id) = unit.faction_id else {
+        return Ok(());
+    };
+    if is_game_master(ctx, unit.game_id) || faction_members(ctx, faction_id).any(|member| member.player == ctx.sender) {
+        Ok(())
+    } else {
+        Err(format!("Unit {} belongs to faction {}, which you are not in", unit.id, faction_id))
+    }
+}
+
+/// Errors unless the sender plays for the faction or is the game master.
+fn check_faction_member(ctx: &ReducerContext, faction: &Faction) -> Result<(), String> {
+    if is_game_master(ctx, faction.game_id) || faction_members(ctx, faction.id).any(|member| member.player == ctx.sender) {
+        Ok(())
+    } else {
+        Err(format!("You are not in {}", faction.name))
+    }
+}
+
+fn faction_members(ctx: &ReducerContext, faction_id: u64) -> impl Iterator<Item = FactionMember> + '_ {
+    ctx.db.faction_member().faction_id().filter(faction_id)
+}
+
+/// Returns the game's faction with this name, creating it in `color` if
+/// there is none yet.
+fn find_or_create_faction(ctx: &ReducerContext, game_id: u64, name: &str, color: &str) -> Faction {
+    match ctx.db.faction().game_id().filter(&game_id).find(|faction| faction.name == name) {
+        Some(faction) => faction,
+        None => {
+            let faction = ctx.db.faction().insert(Faction {
+                id: 0,
+                game_id,
+                name: name.to_string(),
+                color: color.to_string(),
+                score: 0,
+            });
+            log_action(ctx, game_id, "FACTION", format!("Faction {} created", faction.name));
+            faction
+        }
+    }
+}
+
+/// The faction a roster entry deploys for. A faction the import creates takes
+/// on the importing player if they aren't on a side in the game yet; any
+/// other faction has to be their own, unless they are the game master.
+fn roster_faction(ctx: &ReducerContext, game_id: u64, name: &str, color: &str) -> Result<Faction, String> {
+    let created = !ctx.db.faction().game_id().filter(&game_id).any(|faction| faction.name == name);
+    let faction = find_or_create_faction(ctx, game_id, name, color);
+    let on_a_side = ctx.db.faction_member().player().filter(&ctx.sender).any(|member| member.game_id == game_id);
+    if created && !on_a_side && !is_game_master(ctx, game_id) {
+        ctx.db.faction_member().insert(FactionMember {
+            id: 0,
+            faction_id: faction.id,
+            game_id,
+            player: ctx.sender,
+        });
+        log_action(ctx, game_id, "FACTION", format!("{} joined {}", ctx.sender, faction.name));
+    }
+    check_faction_member(ctx, &faction)?;
+    Ok(faction)
+}
+
+#[spacetimedb::reducer]
+pub fn create_faction(ctx: &ReducerContext, game_id: u64, name: String, color: String) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!("Faction names must be 1 to {} characters long", MAX_NAME_LENGTH));
+    }
+    validate_color(&color)?;
+    if ctx.db.faction().game_id().filter(&game_id).any(|faction| faction.name == name) {
+        return Err(format!("There is already a faction called {}", name));
+    }
+    find_or_create_faction(ctx, game_id, &name, &color);
+    Ok(())
+}
+
+/// Recolors a faction and every unit in it. Only the game master may.
+#[spacetimedb::reducer]
+pub fn set_faction_color(ctx: &ReducerContext, faction_id: u64, color: String) -> Result<(), String> {
+    let faction = ctx.db.faction().id().find(faction_id).ok_or(format!("Faction {} not found", faction_id))?;
+    check_game_master(ctx, faction.game_id)?;
+    validate_color(&color)?;
+    for unit in ctx.db.unit().game_id().filter(&faction.game_id) {
+        if unit.faction_id == Some(faction_id) {
+            ctx.db.unit().id().update(Unit { color: color.clone(), ..unit });
+        }
+    }
+    ctx.db.faction().id().update(Faction { color, ..faction });
+    Ok(())
+}
+
+/// Deletes a faction. Its units stay on the table, unaligned and in the
+/// faction's last color. Only the game master may.
+#[spacetimedb::reducer]
+pub fn delete_faction(ctx: &ReducerContext, faction_id: u64) -> Result<(), String> {
+    let faction = ctx.db.faction().id().find(faction_id).ok_or(format!("Faction {} not found", faction_id))?;
+    check_game_master(ctx, faction.game_id)?;
+    for unit in ctx.db.unit().game_id().filter(&faction.game_id) {
+        if unit.faction_id == Some(faction_id) {
+            ctx.db.unit().id().update(Unit { faction_id: None, ..unit });
+        }
+    }
+    for passenger in ctx.db.embarked_unit().game_id().filter(&faction.game_id) {
+        if passenger.unit.faction_id == Some(faction_id) {
+            let unit = Unit { faction_id: None, ..passenger.unit };
+            ctx.db.embarked_unit().unit_id().update(EmbarkedUnit { unit, ..passenger });
+        }
+    }
+    for member in faction_members(ctx, faction_id) {
+        ctx.db.faction_member().id().delete(member.id);
+    }
+    for message in ctx.db.team_message().faction_id().filter(&faction_id) {
+        ctx.db.team_message().id().delete(message.id);
+    }
+    ctx.db.faction().id().delete(faction_id);
+    refresh_visibility(ctx, faction.game_id);
+    log_action(ctx, faction.game_id, "FACTION", format!("Faction {} disbanded", faction.name));
+    Ok(())
+}
+
+/// Puts a player on a faction's side, moving them out of any other faction
+/// in the same game. The game master can assign anyone; other players can
+/// only join a side themselves, and only while they are on none.
+#[spacetimedb::reducer]
+pub fn assign_player(ctx: &ReducerContext, faction_id: u64, player: Identity) -> Result<(), String> {
+    let faction = ctx.db.faction().id().find(faction_id).ok_or(format!("Faction {} not found", faction_id))?;
+    if !is_game_master(ctx, faction.game_id) {
+        if player != ctx.sender {
+            return Err("Only the game master can assign other players".to_string());
+        }
+        if ctx.db.faction_member().player().filter(&player).any(|member| member.game_id == faction.game_id) {
+            return Err(format!("You are already in a faction in game {}; ask the game master to switch", faction.game_id));
+        }
+    }
+    for member in ctx.db.faction_member().player().filter(&player) {
+        if member.game_id == faction.game_id {
+            ctx.db.faction_member().id().delete(member.id);
+        }
+    }
+    ctx.db.faction_member().insert(FactionMember {
+        id: 0,
+        faction_id,
+        game_id: faction.game_id,
+        player,
+    });
+    log_action(ctx, faction.game_id, "FACTION", format!("{} joined {}", player, faction.name));
+    Ok(())
+}
+
+/// Takes a player off their side. Players can leave on their own; only the
+/// game master can remove someone else.
+#[spacetimedb::reducer]
+pub fn unassign_player(ctx: &ReducerContext, game_id: u64, player: Identity) -> Result<(), String> {
+    if player != ctx.sender {
+        check_game_master(ctx, game_id)?;
+    }
+    let member = ctx
+        .db
+        .faction_member()
+        .player()
+        .filter(&player)
+        .find(|member| member.game_id == game_id)
+        .ok_or(format!("{} is not in a faction in game {}", player, game_id))?;
+    ctx.db.faction_member().id().delete(member.id);
+    log_action(ctx, game_id, "FACTION", format!("{} left faction {}", player, member.faction_id));
+    Ok(())
+}
+
+/// Moves a unit to another side, or makes it unaligned with `None`. The
+/// sender must control the unit as it is now and be in the new faction.
+#[spacetimedb::reducer]
+pub fn set_unit_faction(ctx: &ReducerContext, unit_id: u64, faction_id: Option<u64>) -> Result<(), String> {
+    let unit = ctx.db.unit().id().find(unit_id).ok_or(format!("Unit {} not found", unit_id))?;
+    check_unit_control(ctx, unit_id)?;
+    let color = match faction_id {
+        Some(faction_id) => {
+            let faction = ctx.db.faction().id().find(faction_id).ok_or(format!("Faction {} not found", faction_id))?;
+            if faction.game_id != unit.game_id {
+                return Err(format!("Faction {} is in another game", faction_id));
+            }
+            check_faction_member(ctx, &faction)?;
+            faction.color
+        }
+        None => unit.color.clone(),
+    };
+    let game_id = unit.game_id;
+    ctx.db.unit().id().update(Unit { faction_id, color, ..unit });
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+/// Adds victory points to a faction; negative points take them away. Only
+/// the game master keeps score.
+#[spacetimedb::reducer]
+pub fn award_points(ctx: &ReducerContext, faction_id: u64, points: i32, reason: String) -> Result<(), String> {
+    let faction = ctx.db.faction().id().find(faction_id).ok_or(format!("Faction {} not found", faction_id))?;
+    check_game_master(ctx, faction.game_id)?;
+    let score = faction.score.saturating_add(points);
+    log_action(ctx, faction.game_id, "SCORE", format!("🏆 {} {:+} ({}): {} points", faction.name, points, reason, score));
+    ctx.db.faction().id().update(Faction { score, ..faction });
+    Ok(())
+}
+
+/// Sends a message only the sender's faction can read.
+#[spacetimedb::reducer]
+pub fn send_team_message(ctx: &ReducerContext, faction_id: u64, message: String) -> Result<(), String> {
+    let faction = ctx.db.faction().id().find(faction_id).ok_or(format!("Faction {} not found", faction_id))?;
+    if !faction_members(ctx, faction_id).any(|member| member.player == ctx.sender) {
+        return Err(format!("You are not in {}", faction.name));
+    }
+    ctx.db.team_message().insert(TeamMessage {
+        id: 0,
+        faction_id,
+        game_id: faction.game_id,
+        sender: ctx.sender,
+        timestamp: ctx.timestamp,
+        message,
+    });
+    Ok(())
+}
+
+/// Team chat for every faction the caller is in.
+#[spacetimedb::view(name = my_team_messages, public)]
+fn my_team_messages(ctx: &ViewContext) -> Vec<TeamMessage> {
+    ctx.db
+        .faction_member()
+        .player()
+        .filter(&ctx.sender)
+        .flat_map(|member| ctx.db.team_message().faction_id().filter(member.faction_id))
+        .collect()
+}
+
+#[derive(SpacetimeType, Copy, Clone, Debug, PartialEq)]
+pub enum CoherencyMode {
+    /// Moves that break coherency go ahead and the squad is marked as out of it
+    Flag,
+    /// Moves that would break a coherent squad are refused
+    Enforce,
+}
+
+/// Returns whether every model in the squad is within the squad's coherency
+/// distance of enough of the others. Squads smaller than the rule needs only
+/// have to stay together as a whole.
+fn squad_is_coherent(squad: &Squad, members: &[Unit]) -> bool {
+    let needed = (squad.coherency_count as usize).min(members.len().saturating_sub(1));
+    let world = CollisionWorld::new(members, false, None);
+    members.iter().all(|unit| {
+        let Some(shape) = create_shape_obj(&unit.shape_type, &unit.position, &unit.size) else {
+            return true;
+        };
+        let iso = shape_isometry(&unit.shape_type, &unit.position, unit.rotation);
+        let nearby = world.items_within(&*shape, &iso, squad.coherency_distance);
+        nearby.iter().filter(|&&id| id != unit.id).count() >= needed
+    })
+}
+
+fn squad_members<'a>(ctx: &'a ReducerContext, squad: &Squad) -> impl Iterator<Item = Unit> + 'a {
+    ctx.db
+        .squad_member()
+        .squad_id()
+        .filter(squad.id)
+        .filter_map(|member| ctx.db.unit().id().find(member.unit_id))
+}
+
+/// Keeps the `squad_member` index in step with a unit's squad; `None` takes
+/// the unit out of it.
+fn index_squad_member(ctx: &ReducerContext, unit_id: u64, squad_id: Option<u64>) {
+    ctx.db.squad_member().unit_id().delete(unit_id);
+    if let Some(squad_id) = squad_id {
+        ctx.db.squad_member().insert(SquadMember { unit_id, squad_id });
+    }
+}
+
+/// Records a change in a squad's coherency on the squad row and in the log.
+fn set_squad_coherent(ctx: &ReducerContext, squad: Squad, coherent: bool) {
+    if squad.coherent == coherent {
+        return;
+    }
+    let description = if coherent {
+        format!("Squad {} is back in coherency", squad.name)
+    } else {
+        format!("⚠ Squad {} is out of coherency", squad.name)
+    };
+    log_action(ctx, squad.game_id, "COHERENCY", description);
+    ctx.db.squad().id().update(Squad { coherent, ..squad });
+}
+
+/// Evaluates the squad of a unit about to be saved as `moved`. Errors if the
+/// game enforces coherency and the move would break it; otherwise updates the
+/// squad's flag.
+fn check_squad_coherency(ctx: &ReducerContext, moved: &Unit) -> Result<(), String> {
+    let Some(squad) = moved.squad_id.and_then(|squad_id| ctx.db.squad().id().find(squad_id)) else {
+        return Ok(());
+    };
+    let members: Vec<Unit> = squad_members(ctx, &squad)
+        .map(|unit| if unit.id == moved.id { moved.clone() } else { unit })
+        .collect();
+    let coherent = squad_is_coherent(&squad, &members);
+    let mode = ctx.db.games().id().find(squad.game_id).map_or(CoherencyMode::Flag, |game| game.coherency_mode);
+    if !coherent && squad.coherent && mode == CoherencyMode::Enforce {
+        return Err(format!("Moving unit {} would break squad {}'s coherency", moved.id, squad.name));
+    }
+    set_squad_coherent(ctx, squad, coherent);
+    Ok(())
+}
+
+/// Errors unless the sender controls every unit in the squad.
+fn check_squad_control(ctx: &ReducerContext, squad: &Squad) -> Result<(), String> {
+    squad_members(ctx, squad).try_for_each(|unit| check_unit_control(ctx, unit.id))
+}
+
+/// Re-evaluates a squad after its members or its rule changed.
+fn refresh_squad_coherency(ctx: &ReducerContext, squad_id: u64) {
+    let Some(squad) = ctx.db.squad().id().find(squad_id) else {
+        return;
+    };
+    let members: Vec<Unit> = squad_members(ctx, &squad).collect();
+    let coherent = squad_is_coherent(&squad, &members);
+    set_squad_coherent(ctx, squad, coherent);
+}
+
+fn validate_coherency_rule(distance: f32, count: u32) -> Result<(), String> {
+    if !distance.is_finite() || distance < 0.0 || distance > MAX_SHAPE_SIZE as f32 {
+        return Err(format!("Coherency distance must be between 0 and {}", MAX_SHAPE_SIZE));
+    }
+    if count == 0 {
+        return Err("Models must stay close to at least one other model".to_string());
+    }
+    Ok(())
+}
+
+/// Groups units into a squad whose models must each stay within `distance`
+/// of at least `count` others.
+#[spacetimedb::reducer]
+pub fn create_squad(ctx: &ReducerContext, game_id: u64, name: String, distance: f32, count: u32, unit_ids: Vec<u64>) -> Result<(), String> {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(format!("Squad names must be 1 to {} characters long", MAX_NAME_LENGTH));
+    }
+    validate_coherency_rule(distance, count)?;
+    let squad = ctx.db.squad().insert(Squad {
+        id: 0,
+        game_id,
+        name,
+        coherency_distance: distance,
+        coherency_count: count,
+        coherent: true,
+    });
+    let mut previous_squads = Vec::new();
+    for unit_id in unit_ids {
+        let unit = ctx.db.unit().id().find(unit_id).ok_or(format!("Unit {} not found", unit_id))?;
+        if unit.game_id != game_id {
+            return Err(format!("Unit {} is in another game", unit_id));
+        }
+        check_unit_control(ctx, unit_id)?;
+        if let Some(previous) = unit.squad_id.filter(|previous| !previous_squads.contains(previous)) {
+            previous_squads.push(previous);
+        }
+        ctx.db.unit().id().update(Unit { squad_id: Some(squad.id), ..unit });
+        index_squad_member(ctx, unit_id, Some(squad.id));
+    }
+    for previous in previous_squads {
+        refresh_squad_coherency(ctx, previous);
+    }
+    refresh_squad_coherency(ctx, squad.id);
+    Ok(())
+}
+
+/// Changes a squad's coherency rule. Only players who control all of its
+/// units may.
+#[spacetimedb::reducer]
+pub fn set_squad_rule(ctx: &ReducerContext, squad_id: u64, distance: f32, count: u32) -> Result<(), String> {
+    let squad = ctx.db.squad().id().find(squad_id).ok_or(format!("Squad {} not found", squad_id))?;
+    check_squad_control(ctx, &squad)?;
+    validate_coherency_rule(distance, count)?;
+    ctx.db.squad().id().update(Squad { coherency_distance: distance, coherency_count: count, ..squad });
+    refresh_squad_coherency(ctx, squad_id);
+    Ok(())
+}
+
+/// Moves a unit into a squad, or out of its squad with `None`.
+#[spacetimedb::reducer]
+pub fn set_unit_squad(ctx: &ReducerContext, unit_id: u64, squad_id: Option<u64>) -> Result<(), String> {
+    let unit = ctx.db.unit().id().find(unit_id).ok_or(format!("Unit {} not found", unit_id))?;
+    check_unit_control(ctx, unit_id)?;
+    if let Some(squad_id) = squad_id {
+        let squad = ctx.db.squad().id().find(squad_id).ok_or(format!("Squad {} not found", squad_id))?;
+        if squad.game_id != unit.game_id {
+            return Err(format!("Squad {} is in another game", squad_id));
+        }
+    }
+    let previous = unit.squad_id;
+    ctx.db.unit().id().update(Unit { squad_id, ..unit });
+    index_squad_member(ctx, unit_id, squad_id);
+    for squad_id in [previous, squad_id].into_iter().flatten() {
+        refresh_squad_coherency(ctx, squad_id);
+    }
+    Ok(())
+}
+
+/// Disbands a squad; its units stay on the table. Only players who control
+/// all of its units may.
+#[spacetimedb::reducer]
+pub fn delete_squad(ctx: &ReducerContext, squad_id: u64) -> Result<(), String> {
+    let squad = ctx.db.squad().id().find(squad_id).ok_or(format!("Squad {} not found", squad_id))?;
+    check_squad_control(ctx, &squad)?;
+    for unit in squad_members(ctx, &squad) {
+        index_squad_member(ctx, unit.id, None);
+        ctx.db.unit().id().update(Unit { squad_id: None, ..unit });
+    }
+    ctx.db.squad().id().delete(squad_id);
+    Ok(())
+}
+
+/// Switches how the game treats broken coherency. Only the game master may.
+#[spacetimedb::reducer]
+pub fn set_coherency_mode(ctx: &ReducerContext, game_id: u64, mode: CoherencyMode) -> Result<(), String> {
+    let game = ctx.db.games().id().find(game_id).ok_or(format!("Game {} not found", game_id))?;
+    check_game_master(ctx, game_id)?;
+    ctx.db.games().id().update(Game { coherency_mode: mode, ..game });
+    Ok(())
+}
+
+/// How close, edge to edge, a unit must be to its carrier to embark, and the
+/// furthest from it that it is placed when disembarking.
+const EMBARK_DISTANCE: f32 = 10.0;
+
+/// Lets a unit carry up to `capacity` others; zero makes it an ordinary unit
+/// again.
+#[spacetimedb::reducer]
+pub fn set_transport_capacity(ctx: &ReducerContext, unit_id: u64, capacity: u32) -> Result<(), String> {
+    let unit = ctx.db.unit().id().find(unit_id).ok_or(format!("Unit {} not found", unit_id))?;
+    check_unit_control(ctx, unit_id)?;
+    let carried = ctx.db.embarked_unit().carrier_id().filter(&unit_id).count() as u32;
+    if capacity < carried {
+        return Err(format!("Unit {} is carrying {} units, more than {}", unit_id, carried, capacity));
+    }
+    ctx.db.transport().unit_id().delete(unit_id);
+    if capacity > 0 {
+        ctx.db.transport().insert(Transport { unit_id, game_id: unit.game_id, capacity });
+    }
+    Ok(())
+}
+
+/// Takes a unit off the table and into a nearby transport or building. Its
+/// attributes, conditions and memberships are kept for when it disembarks.
+/// The sender must control both the unit and its carrier.
+#[spacetimedb::reducer]
+pub fn embark(ctx: &ReducerContext, unit_id: u64, carrier_id: u64) -> Result<(), String> {
+    let unit = ctx.db.unit().id().find(unit_id).ok_or(format!("Unit {} not found", unit_id))?;
+    let carrier = ctx.db.unit().id().find(carrier_id).ok_or(format!("Unit {} not found", carrier_id))?;
+    check_control(ctx, &unit)?;
+    check_control(ctx, &carrier)?;
+    if unit_id == carrier_id || unit.game_id != carrier.game_id {
+        return Err(format!("Unit {} cannot embark in unit {}", unit_id, carrier_id));
+    }
+    let transport = ctx.db.transport().unit_id().find(carrier_id).ok_or(format!("Unit {} cannot carry units", carrier_id))?;
+    if ctx.db.embarked_unit().carrier_id().filter(&carrier_id).count() as u32 >= transport.capacity {
+        return Err(format!("Unit {} is full", carrier_id));
+    }
+    if ctx.db.embarked_unit().carrier_id().filter(&unit_id).next().is_some() {
+        return Err(format!("Unit {} is carrying units and cannot embark", unit_id));
+    }
+    if let Some(kind) = immobilizing_condition(ctx, unit_id) {
+        return Err(format!("Unit {} is {:?}", unit_id, kind));
+    }
+    match measure_items(&unit, &carrier) {
+        Some((gap, _)) if gap <= EMBARK_DISTANCE => {}
+        _ => return Err(format!("Unit {} is not within {} of unit {}", unit_id, EMBARK_DISTANCE, carrier_id)),
+    }
+
+    ctx.db.unit().id().delete(unit_id);
+    unindex_piece(ctx, Layer::Unit, unit_id);
+    index_squad_member(ctx, unit_id, None);
+    ctx.db.planned_path().unit_id().delete(unit_id);
+    ctx.db.selected_unit().id().delete(unit_id);
+    let (game_id, squad_id) = (unit.game_id, unit.squad_id);
+    ctx.db.embarked_unit().insert(EmbarkedUnit { unit_id, carrier_id, game_id, unit });
+    if let Some(squad_id) = squad_id {
+        refresh_squad_coherency(ctx, squad_id);
+    }
+    refresh_visibility(ctx, game_id);
+    log_action(ctx, game_id, "EMBARK", format!("Unit {} embarked in unit {}", unit_id, carrier_id));
+    Ok(())
+}
+
+/// Finds a free spot for a disembarking unit within `EMBARK_DISTANCE` of its
+/// carrier, searching outwards from the carrier in rings.
+fn disembark_position(ctx: &ReducerContext, unit: &Unit, carrier: &Unit) -> Option<Vec<Position>> {
+    let extent = |piece: &Unit| piece_aabb(piece).map_or(0.0, |aabb| aabb.half_extents().norm());
+    let reach = extent(carrier) + extent(unit) + EMBARK_DISTANCE;
+    let from = shape_center(&unit.shape_type, &unit.position);
+    let to = shape_center(&carrier.shape_type, &carrier.position);
+    const DIRECTIONS: u32 = 16;
+    (0..=reach as u32).step_by(2).find_map(|radius| {
+        (0..DIRECTIONS).find_map(|step| {
+            let angle = step as f32 / DIRECTIONS as f32 * std::f32::consts::TAU;
+            let dx = (to.x + radius as f32 * angle.cos() - from.x).round() as i32;
+            let dy = (to.y + radius as f32 * angle.sin() - from.y).round() as i32;
+            if unit.position.iter().any(|p| p.x as i32 + dx < 0 || p.y as i32 + dy < 0) {
+                return None;
+            }
+            let placed = Unit { position: translate_positions(&unit.position, dx, dy), ..unit.clone() };
+            let in_range = measure_items(&placed, carrier).is_some_and(|(gap, _)| gap <= EMBARK_DISTANCE);
+            let valid = validate_piece(&placed.shape_type, &placed.position, &placed.size, placed.rotation, &placed.color).is_ok();
+            (in_range && valid && !unit_blocked(ctx, &placed)).then_some(placed.position)
+        })
+    })
+}
+
+fn disembark_unit(ctx: &ReducerContext, passenger: EmbarkedUnit) -> Result<(), String> {
+    let carrier = ctx.db.unit().id().find(passenger.carrier_id).ok_or(format!("Unit {} not found", passenger.carrier_id))?;
+    let position = disembark_position(ctx, &passenger.unit, &carrier)
+        .ok_or(format!("There is no room for unit {} next to unit {}", passenger.unit_id, carrier.id))?;
+    ctx.db.embarked_unit().unit_id().delete(passenger.unit_id);
+    let unit = insert_unit_row(ctx, Unit { position, ..passenger.unit });
+    if let Some(squad_id) = unit.squad_id {
+        refresh_squad_coherency(ctx, squad_id);
+    }
+    refresh_visibility(ctx, unit.game_id);
+    log_action(ctx, unit.game_id, "EMBARK", format!("Unit {} disembarked from unit {}", unit.id, carrier.id));
+    Ok(())
+}
+
+/// Puts an embarked unit back on the table next to wherever its carrier is
+/// now. The sender must control the passenger.
+#[spacetimedb::reducer]
+pub fn disembark(ctx: &ReducerContext, unit_id: u64) -> Result<(), String> {
+    let passenger = ctx.db.embarked_unit().unit_id().find(unit_id).ok_or(format!("Unit {} is not embarked", unit_id))?;
+    check_control(ctx, &passenger.unit)?;
+    disembark_unit(ctx, passenger)
+}
+
+/// Disembarks every unit a carrier holds; none leave unless all fit and the
+/// sender controls them all.
+#[spacetimedb::reducer]
+pub fn disembark_all(ctx: &ReducerContext, carrier_id: u64) -> Result<(), String> {
+    check_unit_control(ctx, carrier_id)?;
+    let passengers: Vec<EmbarkedUnit> = ctx.db.embarked_unit().carrier_id().filter(&carrier_id).collect();
+    for passenger in &passengers {
+        check_control(ctx, &passenger.unit)?;
+    }
+    for passenger in passengers {
+        disembark_unit(ctx, passenger)?;
+    }
+    Ok(())
+}
+
+/// Vision radius given to new units.
+const DEFAULT_VISION_RADIUS: u32 = 200;
+/// How close an enemy has to come to spot a Hidden unit.
+const HIDDEN_SPOT_DISTANCE: u32 = 30;
+
+/// Returns true if the target is within the observer's vision radius, or
+/// spotting distance if it is hidden, and at least one sight line to it
+/// misses all impassable terrain.
+fn unit_sees(observer: &Unit, target: &Unit, target_hidden: bool, blocking_terrain_world: &CollisionWorld) -> bool {
+    let range = if target_hidden { observer.vision_radius.min(HIDDEN_SPOT_DISTANCE) } else { observer.vision_radius };
+    if !measure_items(observer, target).is_some_and(|(gap, _)| gap <= range as f32) {
+        return false;
+    }
+    let eye = shape_center(&observer.shape_type, &observer.position);
+    sight_points(&target.shape_type, &target.position, &target.size, target.rotation, eye)
+        .into_iter()
+        .any(|point| !blocking_terrain_world.segment_hits(eye, point))
+}
+
+/// Recomputes which enemy units each faction in the game has in sight, and
+/// clears them all when fog of war is off. Hidden units are only spotted up
+/// close. Each unit only looks at the units the spatial index puts within its
+/// vision radius.
+fn refresh_visibility(ctx: &ReducerContext, game_id: u64) {
+    let mut seen: std::collections::BTreeSet<(u64, u64)> = std::collections::BTreeSet::new();
+    if ctx.db.games().id().find(game_id).is_some_and(|game| game.fog_of_war) {
+        let terrains: Vec<Terrain> = ctx.db.terrain().game_id().filter(&game_id).collect();
+        let blocking_terrain_world = CollisionWorld::new(&terrains, true, None);
+        let hidden: Vec<u64> = ctx
+            .db
+            .condition()
+            .game_id()
+            .filter(&game_id)
+            .filter(|condition| condition.kind == ConditionKind::Hidden)
+            .map(|condition| condition.unit_id)
+            .collect();
+        for observer in ctx.db.unit().game_id().filter(&game_id) {
+            let Some(faction_id) = observer.faction_id else {
+                continue;
+            };
+            // Nothing is further away than the far side of the board
+            let reach = observer.vision_radius.min(BOARD_WIDTH + BOARD_HEIGHT);
+            let area = swept_aabb(&observer, 0, 0).loosened(reach as f32);
+            for target in nearby_units(ctx, game_id, &area) {
+                let enemy = target.faction_id.is_some_and(|id| id != faction_id);
+                if !enemy || seen.contains(&(faction_id, target.id)) {
+                    continue;
+                }
+                if unit_sees(&observer, &target, hidden.contains(&target.id), &blocking_terrain_world) {
+                    seen.insert((faction_id, target.id));
+                }
+            }
+        }
+    }
+
+    for sighting in ctx.db.sighting().game_id().filter(&game_id) {
+        if !seen.remove(&(sighting.faction_id, sighting.unit_id)) {
+            ctx.db.sighting().id().delete(sighting.id);
+        }
+    }
+    for (faction_id, unit_id) in seen {
+        ctx.db.sighting().insert(Sighting { id: 0, game_id, faction_id, unit_id });
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn set_fog_of_war(ctx: &ReducerContext, game_id: u64, enabled: bool) -> Result<(), String> {
+    let game = ctx.db.games().id().find(game_id).ok_or(format!("Game {} not found", game_id))?;
+    check_game_master(ctx, game_id)?;
+    ctx.db.games().id().update(Game { fog_of_war: enabled, ..game });
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_vision_radius(ctx: &ReducerContext, unit_id: u64, radius: u32) -> Result<(), String> {
+    let unit = ctx.db.unit().id().find(unit_id).ok_or(format!("Unit {} not found", unit_id))?;
+    check_unit_control(ctx, unit_id)?;
+    let game_id = unit.game_id;
+    ctx.db.unit().id().update(Unit { vision_radius: radius, ..unit });
+    refresh_visibility(ctx, game_id);
+    Ok(())
+}
+
+/// Who is looking at a game, for the views that hide pieces and the reducers
+/// that refuse to touch them.
+struct Viewer {
+    identity: Identity,
+    faction_id: Option<u64>,
+    is_gm: bool,
+}
+
+impl Viewer {
+    fn new(ctx: &ViewContext, game: &Game) -> Self {
+        Viewer {
+            identity: ctx.sender,
+            faction_id: ctx
+                .db
+                .faction_member()
+                .player()
+                .filter(&ctx.sender)
+                .find(|member| member.game_id == game.id)
+                .map(|member| member.faction_id),
+            is_gm: game.gm == Some(ctx.sender),
+        }
+    }
+
+    /// The caller of a reducer, so it can refuse to act on pieces the
+    /// caller's views leave out.
+    fn sender(ctx: &ReducerContext, game: &Game) -> Self {
+        Viewer {
+            identity: ctx.sender,
+            faction_id: ctx
+                .db
+                .faction_member()
+                .player()
+                .filter(&ctx.sender)
+                .find(|member| member.game_id == game.id)
+                .map(|member| member.faction_id),
+            is_gm: game.gm == Some(ctx.sender),
+        }
+    }
+
+    /// Whether the viewer may see a piece with this visibility flag. Units
+    /// also count as the viewer's own when they are in the viewer's faction.
+    fn may_see(&self, visibility: Visibility, owner: Option<Identity>, faction_id: Option<u64>) -> bool {
+        match visibility {
+            _ if self.is_gm => true,
+            Visibility::Everyone => true,
+            Visibility::Owner => owner == Some(self.identity) || (faction_id.is_some() && faction_id == self.faction_id),
+            Visibility::GmOnly => false,
+        }
+    }
+
+    /// Whether the viewer may see a unit. Under fog of war that takes the
+    /// unit being their own faction's, unaligned, or `sighted` by their
+    /// faction; the game master sees everything.
+    fn may_see_unit(&self, game: &Game, unit: &Unit, sighted: bool) -> bool {
+        let in_sight = !game.fog_of_war
+            || self.is_gm
+            || unit.faction_id.is_none()
+            || unit.faction_id == self.faction_id
+            || sighted;
+        in_sight && self.may_see(unit.visibility, unit.owner, unit.faction_id)
+    }
+}
+
+/// Whether the unit is in the sender's `visible_units`.
+fn sender_sees_unit(ctx: &ReducerContext, unit: &Unit) -> bool {
+    let Some(game) = ctx.db.games().id().find(unit.game_id) else {
+        return false;
+    };
+    let viewer = Viewer::sender(ctx, &game);
+    let sighted = match viewer.faction_id {
+        Some(faction_id) if game.fog_of_war => {
+            ctx.db.sighting().faction_id().filter(&faction_id).any(|sighting| sighting.unit_id == unit.id)
+        }
+        _ => false,
+    };
+    viewer.may_see_unit(&game, unit, sighted)
+}
+
+/// The unit with this id, if it is in the sender's `visible_units`; units the
+/// sender can't see are reported as missing so they can't be probed for.
+fn find_visible_unit(ctx: &ReducerContext, unit_id: u64) -> Result<Unit, String> {
+    ctx.db
+        .unit()
+        .id()
+        .find(unit_id)
+        .filter(|unit| sender_sees_unit(ctx, unit))
+        .ok_or(format!("Unit {} not found", unit_id))
+}
+
+/// Whether a terrain, underlay or overlay piece is in the sender's views.
+fn sender_sees_piece(ctx: &ReducerContext, game_id: u64, owner: Option<Identity>, visibility: Visibility) -> bool {
+    ctx.db
+        .games()
+        .id()
+        .find(game_id)
+        .is_some_and(|game| Viewer::sender(ctx, &game).may_see(visibility, owner, None))
+}
+
+/// The visibility a newly placed piece starts with: everyone by default, and
+/// only the game master may place pieces hidden from everyone but themselves.
+fn placement_visibility(ctx: &ReducerContext, game_id: u64, visibility: Option<Visibility>) -> Result<Visibility, String> {
+    let visibility = visibility.unwrap_or(Visibility::Everyone);
+    if visibility == Visibility::GmOnly && !is_game_master(ctx, game_id) {
+        return Err("Only the game master can place pieces only they can see".to_string());
+    }
+    Ok(visibility)
+}
+
+/// Every game; views can only read tables through an index.
+fn all_games(ctx: &ViewContext) -> impl Iterator<Item = Game> + '_ {
+    ctx.db.games().fog_of_war().filter(&false).chain(ctx.db.games().fog_of_war().filter(&true))
+}
+
+/// The units in a game the caller may see.
+fn visible_units_in(ctx: &ViewContext, game: &Game) -> Vec<Unit> {
+    let viewer = Viewer::new(ctx, game);
+    let sighted: Vec<u64> = match viewer.faction_id {
+        Some(faction_id) if game.fog_of_war => {
+            ctx.db.sighting().faction_id().filter(&faction_id).map(|sighting| sighting.unit_id).collect()
+        }
+        _ => Vec::new(),
+    };
+    ctx.db
+        .unit()
+        .game_id()
+        .filter(&game.id)
+        .filter(|unit| viewer.may_see_unit(game, unit, sighted.contains(&unit.id)))
+        .collect()
+}
+
+/// The units the caller may see. Under fog of war that is their own
+/// faction's units, unaligned units and the enemy units their faction has in
+/// sight; the game master sees everything.
+#[spacetimedb::view(name = visible_units, public)]
+fn visible_units(ctx: &ViewContext) -> Vec<Unit> {
+    all_games(ctx).flat_map(|game| visible_units_in(ctx, &game)).collect()
+}
+
+/// Planned paths of the units the caller may see.
+#[spacetimedb::view(name = visible_planned_paths, public)]
+fn visible_planned_paths(ctx: &ViewContext) -> Vec<PlannedPath> {
+    all_games(ctx)
+        .flat_map(|game| visible_units_in(ctx, &game))
+        .filter_map(|unit| ctx.db.planned_path().unit_id().find(unit.id))
+        .collect()
+}
+
+/// Drags in progress on the units the caller may see.
+#[spacetimedb::view(name = visible_selected_units, public)]
+fn visible_selected_units(ctx: &ViewContext) -> Vec<SelectedUnit> {
+    all_games(ctx)
+        .flat_map(|game| visible_units_in(ctx, &game))
+        .filter_map(|unit| ctx.db.selected_unit().id().find(unit.id))
+        .collect()
+}
+
+/// Passengers the caller may see. Off the table nobody has them in sight,
+/// so under fog of war that leaves the caller's own side and unaligned units.
+#[spacetimedb::view(name = visible_embarked_units, public)]
+fn visible_embarked_units(ctx: &ViewContext) -> Vec<EmbarkedUnit> {
+    let mut visible = Vec::new();
+    for game in all_games(ctx) {
+        let viewer = Viewer::new(ctx, &game);
+        let embarked = ctx.db.embarked_unit().game_id().filter(&game.id);
+        visible.extend(embarked.filter(|embarked| viewer.may_see_unit(&game, &embarked.unit, false)));
+    }
+    visible
+}
+
+/// Attributes of the units the caller may see.
+#[spacetimedb::view(name = visible_unit_attributes, public)]
+fn visible_unit_attributes(ctx: &ViewContext) -> Vec<UnitAttribute> {
+    all_games(ctx)
+        .flat_map(|game| visible_units_in(ctx, &game))
+        .flat_map(|unit| ctx.db.unit_attribute().unit_id().filter(unit.id))
+        .collect()
+}
+
+/// Conditions on the units the caller may see.
+#[spacetimedb::view(name = visible_conditions, public)]
+fn visible_conditions(ctx: &ViewContext) -> Vec<Condition> {
+    all_games(ctx)
+        .flat_map(|game| visible_units_in(ctx, &game))
+        .flat_map(|unit| ctx.db.condition().unit_id().filter(unit.id))
+        .collect()
+}
+
+/// Facing arcs of the units the caller may see.
+#[spacetimedb::view(name = visible_unit_arcs, public)]
+fn visible_unit_arcs(ctx: &ViewContext) -> Vec<UnitArcs> {
+    all_games(ctx)
+        .flat_map(|game| visible_units_in(ctx, &game))
+        .filter_map(|unit| ctx.db.unit_arcs().unit_id().find(unit.id))
+        .collect()
+}
+
+/// Transport capacities of the units the caller may see.
+#[spacetimedb::view(name = visible_transports, public)]
+fn visible_transports(ctx: &ViewContext) -> Vec<Transport> {
+    all_games(ctx)
+        .flat_map(|game| visible_units_in(ctx, &game))
+        .filter_map(|unit| ctx.db.transport().unit_id().find(unit.id))
+        .collect()
+}
+
+/// Squads with a unit the caller may see; the game master sees them all.
+#[spacetimedb::view(name = visible_squads, public)]
+fn visible_squads(ctx: &ViewContext) -> Vec<Squad> {
+    let mut visible = Vec::new();
+    for game in all_games(ctx) {
+        if Viewer::new(ctx, &game).is_gm {
+            visible.extend(ctx.db.squad().game_id().filter(&game.id));
+            continue;
+        }
+        let mut squad_ids: Vec<u64> = visible_units_in(ctx, &game).iter().filter_map(|unit| unit.squad_id).collect();
+        squad_ids.sort_unstable();
+        squad_ids.dedup();
+        visible.extend(squad_ids.into_iter().filter_map(|squad_id| ctx.db.squad().id().find(squad_id)));
+    }
+    visible
+}
+
+#[spacetimedb::view(name = visible_terrains, public)]
+fn visible_terrains(ctx: &ViewContext) -> Vec<Terrain> {
+    let mut visible = Vec::new();
+    for game in all_games(ctx) {
+        let viewer = Viewer::new(ctx, &game);
+        visible.extend(ctx.db.terrain().game_id().filter(&game.id).filter(|t| viewer.may_see(t.visibility, t.owner, None)));
+    }
+    visible
+}
+
+#[spacetimedb::view(name = visible_underlays, public)]
+fn visible_underlays(ctx: &ViewContext) -> Vec<Underlay> {
+    let mut visible = Vec::new();
+    for game in all_games(ctx) {
+        let viewer = Viewer::new(ctx, &game);
+        visible.extend(ctx.db.underlay().game_id().filter(&game.id).filter(|u| viewer.may_see(u.visibility, u.owner, None)));
+    }
+    visible
+}
+
+#[spacetimedb::view(name = visible_overlays, public)]
+fn visible_overlays(ctx: &ViewContext) -> Vec<Overlay> {
+    let mut visible = Vec::new();
+    for game in all_games(ctx) {
+        let viewer = Viewer::new(ctx, &game);
+        visible.extend(ctx.db.overlay().game_id().filter(&game.id).filter(|o| viewer.may_see(o.visibility, o.owner, None)));
+    }
+    visible
+}
+
+#[derive(SpacetimeType, Copy, Clone, Debug, PartialEq)]
+pub enum Visibility {
+    Everyone,
+    /// The player who placed the piece, their faction for units, and the GM
+    Owner,
+    /// Only the game master, for traps, ambushes and secret doors
+    GmOnly,
+}
+
+/// Hands the game master role to another player, or gives it up with
+/// `None`. While nobody holds the role anyone may take it, but only before
+/// any player has joined a faction, so it can't be claimed mid-game to see
+/// through the fog of war.
+#[spacetimedb::reducer]
+pub fn set_game_master(ctx: &ReducerContext, game_id: u64, gm: Option<Identity>) -> Result<(), String> {
+    let game = ctx.db.games().id().find(game_id).ok_or(format!("Game {} not found", game_id))?;
+    match game.gm {
+        Some(current) if current != ctx.sender => {
+            return Err(format!("Only the game master of game {} can hand the role over", game_id));
+        }
+        None if ctx.db.faction_member().game_id().filter(&game_id).next().is_some() => {
+            return Err(format!("Game {} is under way; the game master role can no longer be claimed", game_id));
+        }
+        _ => {}
+    }
+    ctx.db.games().id().update(Game { gm, ..game });
+    Ok(())
+}
+
+fn is_game_master(ctx: &ReducerContext, game_id: u64) -> bool {
+    ctx.db.games().id().find(game_id).is_some_and(|game| game.gm == Some(ctx.sender))
+}
+
+/// Errors unless the sender is the game's game master.
+fn check_game_master(ctx: &ReducerContext, game_id: u64) -> Result<(), String> {
+    if is_game_master(ctx, game_id) {
+        Ok(())
+    } else {
+        Err(format!("Only the game master of game {} can do that", game_id))
+    }
+}
+
+/// Game, owner and visibility of any piece, by layer.
+fn piece_visibility(ctx: &ReducerContext, layer: Layer, id: u64) -> Option<(u64, Option<Identity>, Visibility)> {
+    match layer {
+        Layer::Unit => ctx.db.unit().id().find(id).map(|u| (u.game_id, u.owner, u.visibility)),
+        Layer::Terrain => ctx.db.terrain().id().find(id).map(|t| (t.game_id, t.owner, t.visibility)),
+        Layer::Underlay => ctx.db.underlay().id().find(id).map(|u| (u.game_id, u.owner, u.visibility)),
+        Layer::Overlay => ctx.db.overlay().id().find(id).map(|o| (o.game_id, o.owner, o.visibility)),
+    }
+}
+
+/// Changes who can see a piece. The game master can set anything; owners
+/// can only switch their own pieces between `Everyone` and `Owner`, and not
+/// once the GM has hidden them.
+#[spacetimedb::reducer]
+pub fn set_visibility(ctx: &ReducerContext, layer: Layer, id: u64, visibility: Visibility) -> Result<(), String> {
+    let (game_id, owner, current) = piece_visibility(ctx, layer, id).ok_or(format!("{:?} {} not found", layer, id))?;
+    let is_gm = is_game_master(ctx, game_id);
+    let is_owner = owner == Some(ctx.sender);
+    let allowed = is_gm || (is_owner && current != Visibility::GmOnly && visibility != Visibility::GmOnly);
+    if !allowed {
+        return Err(format!("Only the game master can make {:?} {} {:?}", layer, id, visibility));
+    }
+    match layer {
+        Layer::Unit => {
+            let unit = ctx.db.unit().id().find(id).ok_or(format!("Unit {} not found", id))?;
+            ctx.db.unit().id().update(Unit { visibility, ..unit });
+        }
+        Layer::Terrain => {
+            let terrain = ctx.db.terrain().id().find(id).ok_or(format!("Terrain {} not found", id))?;
+            ctx.db.terrain().id().update(Terrain { visibility, ..terrain });
+        }
+        Layer::Underlay => {
+            let underlay = ctx.db.underlay().id().find(id).ok_or(format!("Underlay {} not found", id))?;
+            ctx.db.underlay().id().update(Underlay { visibility, ..underlay });
+        }
+        Layer::Overlay => {
+            let overlay = ctx.db.overlay().id().find(id).ok_or(format!("Overlay {} not found", id))?;
+            ctx.db.overlay().id().update(Overlay { visibility, ..overlay });
+        }
+    }
+    if visibility == Visibility::Everyone && current != Visibility::Everyone {
+        log_action(ctx, game_id, "REVEAL", format!("👁 {:?} {} revealed", layer, id));
+    }
+    Ok(())
+}
+
+/// Shows a hidden piece to everyone, such as a sprung trap or an ambush.
+#[spacetimedb::reducer]
+pub fn reveal(ctx: &ReducerContext, layer: Layer, id: u64) -> Result<(), String> {
+    set_visibility(ctx, layer, id, Visibility::Everyone)
+}
+
+#[derive(SpacetimeType, Copy, Clone, Debug, PartialEq)]
+pub enum TerrainKind {
+    Static,
+    Door,
+    Gate,
+    Bridge,
+}
+
+/// Turns a terrain piece into a door, gate or bridge, or back into plain
+/// terrain. Only the game master and whoever placed it may.
+#[spacetimedb::reducer]
+pub fn set_terrain_kind(ctx: &ReducerContext, terrain_id: u64, kind: TerrainKind) -> Result<(), String> {
+    let terrain = ctx
+        .db
+        .terrain()
+        .id()
+        .find(terrain_id)
+        .filter(|t| sender_sees_piece(ctx, t.game_id, t.owner, t.visibility))
+        .ok_or(format!("Terrain {} not found", terrain_id))?;
+    if terrain.owner != Some(ctx.sender) && !is_game_master(ctx, terrain.game_id) {
+        return Err(format!("Only the game master or its owner can change what terrain {} is", terrain_id));
+    }
+    log_action(ctx, terrain.game_id, "TERRAIN", format!("Terrain {} set to {:?}", terrain_id, kind));
+    ctx.db.terrain().id().update(Terrain { kind, ..terrain });
+    Ok(())
+}
+
+/// Opens a closed door, gate or bridge, making it traversable, or closes an
+/// open one. Closing is refused while a unit stands in the way.
+#[spacetimedb::reducer]
+pub fn toggle_terrain(ctx: &ReducerContext, terrain_id: u64) -> Result<(), String> {
+    // Secret doors stay secret until the GM reveals them
+    let terrain = ctx
+        .db
+        .terrain()
+        .id()
+        .find(terrain_id)
+        .filter(|t| sender_sees_piece(ctx, t.game_id, t.owner, t.visibility))
+        .ok_or(format!("Terrain {} not found", terrain_id))?;
+    if terrain.kind == TerrainKind::Static {
+        return Err(format!("Terrain {} cannot be opened or closed", terrain_id));
+    }
+    let toggled = Terrain { traversable: !terrain.traversable, ..terrain };
+    if terrain_blocks_units(ctx, &toggled) {
+        return Err(format!("{:?} {} cannot close while a unit is in the way", toggled.kind, terrain_id));
+    }
+
+    let game_id = toggled.game_id;
+    let description = format!(
+        "🚪 {:?} {} {}",
+        toggled.kind,
+        terrain_id,
+        if toggled.traversable { "opened" } else { "closed" }
+    );
+    update_terrain_row(ctx, toggled);
+    refresh_visibility(ctx, game_id);
+    log_action(ctx, game_id, "TERRAIN", description);
+    Ok(())
+}
+
+/// A placeable area-of-effect template. `size` is the diameter of a blast,
+/// the side of a square and the length of a cone or line; `width` is the far
+/// end of a cone or the width of a line. `direction` is in degrees, clockwise
+/// from the positive x axis.
+#[spacetimedb::table(name = area_template, public)]
+pub struct AreaTemplate {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    game_id: u64,
+    kind: TemplateKind,
+    origin: Position,
+    size: u32,
+    width: u32,
+    direction: f32,
+    color: String,
+    respect_line_of_sight: bool,
+}
+
+impl Template for AreaTemplate {
+    fn kind(&self) -> TemplateKind { self.kind }
+    fn origin(&self) -> &Position { &self.origin }
+    fn size(&self) -> u32 { self.size }
+    fn width(&self) -> u32 { self.width }
+    fn direction(&self) -> f32 { self.direction }
+}
+
+/// The latest line-of-sight check each player asked for in a game; private,
+/// and read through the `my_los_results` view.
+#[spacetimedb::table(name = los_result)]
+pub struct LosResult {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    game_id: u64,
+    #[index(btree)]
+    requester: Identity,
+    from_unit_id: u64,
+    to_unit_id: u64,
+    status: LineOfSight,
+    timestamp: Timestamp,
+}
+
+#[spacetimedb::view(name = my_los_results, public)]
+fn my_los_results(ctx: &ViewContext) -> Vec<LosResult> {
+    ctx.db.los_result().requester().filter(&ctx.sender).collect()
+}
+
+/// Front and rear arc widths in degrees for a unit; the flank arcs take up
+/// the rest of the circle. Private; read through the `visible_unit_arcs` view.
+#[spacetimedb::table(name = unit_arcs)]
+pub struct UnitArcs {
+    #[primary_key]
+    unit_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    front: f32,
+    rear: f32,
+}
+
+/// Private; read through the `visible_planned_paths` view.
+#[spacetimedb::table(name = planned_path)]
+pub struct PlannedPath {
+    #[primary_key]
+    unit_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    waypoints: Vec<Position>,
+}
+
+/// Broad-phase index: one row for every grid cell a piece's bounding box
+/// touches, so collision and hit tests only load the pieces near them
+/// instead of the whole game.
+#[spacetimedb::table(name = spatial_cell, index(name = game_cell, btree(columns = [game_id, cell_x, cell_y])))]
+pub struct SpatialCell {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    game_id: u64,
+    cell_x: i32,
+    cell_y: i32,
+    layer: Layer,
+    #[index(btree)]
+    item_id: u64,
+}
+
+/// A unit being dragged. Private; read through the `visible_selected_units`
+/// view.
+#[spacetimedb::table(name = selected_unit)]
+pub struct SelectedUnit {
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    game_id: u64,
+    /// Player dragging the unit
+    #[index(btree)]
+    selector: Identity,
+    start_x: u32,
+    start_y: u32,
+    offset_x: u32,
+    offset_y: u32,
+    moved: bool,
+    /// Where the unit stood when the drag began
+    start_position: Vec<Position>,
+}
+
+/// A named stat on a unit, such as wounds, armor, morale or ammo. Private;
+/// read through the `visible_unit_attributes` view.
+#[spacetimedb::table(name = unit_attribute)]
+pub struct UnitAttribute {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    unit_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    name: String,
+    value: AttributeValue,
+    /// Upper limit for numeric values
+    max: Option<f64>,
+}
+
+/// A status effect on a unit. Stunned and pinned units cannot be moved.
+/// Private; read through the `visible_conditions` view.
+#[spacetimedb::table(name = condition)]
+pub struct Condition {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    unit_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    kind: ConditionKind,
+    /// Rounds left, counting the current one; none lasts until removed
+    remaining_rounds: Option<u32>,
+    /// How many times a stacking condition has been applied
+    stacks: u32,
+}
+
+/// One side in a game, such as a team in a two-versus-two match.
+#[spacetimedb::table(name = faction, public)]
+pub struct Faction {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    game_id: u64,
+    name: String,
+    /// Color of every unit in the faction
+    color: String,
+    /// Victory points
+    score: i32,
+}
+
+/// A player playing for a faction. Players are in at most one faction per
+/// game.
+#[spacetimedb::table(name = faction_member, public)]
+pub struct FactionMember {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    faction_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    #[index(btree)]
+    player: Identity,
+}
+
+/// Team chat; private, and read through the `my_team_messages` view so each
+/// player only receives their own factions' messages.
+#[derive(Clone, Debug)]
+#[spacetimedb::table(name = team_message)]
+pub struct TeamMessage {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    faction_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    sender: Identity,
+    timestamp: Timestamp,
+    message: String,
+}
+
+/// Units that move and fight together and must keep in coherency. Private;
+/// read through the `visible_squads` view.
+#[spacetimedb::table(name = squad)]
+pub struct Squad {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    game_id: u64,
+    name: String,
+    /// Furthest a model may be, edge to edge, from the models it relies on
+    coherency_distance: f32,
+    /// How many other models each model must be within that distance of
+    coherency_count: u32,
+    /// Whether the squad met its coherency rule after the last change
+    coherent: bool,
+}
+
+/// Which squad each unit on the table is in, so a squad's members can be
+/// looked up through an index; `Unit::squad_id` is optional and optional
+/// columns can't be filtered on.
+#[spacetimedb::table(name = squad_member)]
+pub struct SquadMember {
+    #[primary_key]
+    unit_id: u64,
+    #[index(btree)]
+    squad_id: u64,
+}
+
+/// A unit that can carry others, such as a vehicle or a garrisonable building.
+/// Private; read through the `visible_transports` view.
+#[spacetimedb::table(name = transport)]
+pub struct Transport {
+    #[primary_key]
+    unit_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    capacity: u32,
+}
+
+/// A unit taken off the table into a transport. The row keeps the whole unit,
+/// id included, so it comes back unchanged apart from where it is placed.
+/// Private; read through the `visible_embarked_units` view.
+#[spacetimedb::table(name = embarked_unit)]
+pub struct EmbarkedUnit {
+    #[primary_key]
+    unit_id: u64,
+    #[index(btree)]
+    carrier_id: u64,
+    #[index(btree)]
+    game_id: u64,
+    unit: Unit,
+}
+
+/// An enemy unit a faction has in sight under fog of war. Private; read
+/// through the `visible_units` view.
+#[spacetimedb::table(name = sighting)]
+pub struct Sighting {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    #[index(btree)]
+    game_id: u64,
+    #[index(btree)]
+    faction_id: u64,
+    unit_id: u64,
+}
+
+/// A reusable unit profile, for placing several identical models at once.
+#[spacetimedb::table(name = unit_template, public)]
+pub struct UnitTemplate {
+    #[auto_inc]
+    #[primary_key]
+    id: u64,
+    /// Game the template belongs to; shared by every game if none
+    game_id: Option<u64>,
+    name: String,
+    shape_type: ShapeType,
+    size: Vec<u32>,
+    color: String,
+    /// Outline as first placed; spawning moves the first position to the target
+    position: Vec<Position>,
+    text: Option<TextContent>,
+    /// Attributes every spawned unit starts with
+    attributes: Vec<TemplateAttribute>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_color_accepts_css_forms() {
+        for color in [
+            "#fff",
+            "#ffff",
+            "#A1b2C3",
+            "#a1b2c3d4",
+            "rgb(255, 0, 0)",
+            "rgba(0,0,0,1)",
+            "RGB(10 20 30 / 50%)",
+            "hsl(120deg, 50%, 50%)",
+            "hsla(120, 50%, 50%, 0.5)",
+            "red",
+            "  transparent  ",
+        ] {
+            assert_eq!(validate_color(color), Ok(()), "{}", color);
+        }
+    }
+
+    #[test]
+    fn validate_color_rejects_malformed_colors() {
+        for color in [
+            "",
+            "#",
+            "#12345",
+            "#ggg",
+            "rgb(1, 2)",
+            "rgb(1, 2, 3, 4, 5)",
+            "rgb(1, 2, x)",
+            "rgb(1, 2, 3",
+            "cmyk(1, 2, 3, 4)",
+            "dark red",
+            "red;",
+        ] {
+            assert!(validate_color(color).is_err(), "{}", color);
+        }
+    }
+
+    #[test]
+    fn validate_piece_lets_lines_run_along_the_edge() {
+        for terrain in border_terrain_lines(1) {
+            let result = validate_piece(&terrain.shape_type, &terrain.position, &terrain.size, terrain.rotation, &terrain.color);
+            assert_eq!(result, Ok(()), "{:?}", terrain.position);
+        }
+        let edge = [Position { x: 0, y: 0 }, Position { x: 100, y: 0 }];
+        assert_eq!(validate_piece(&ShapeType::Line, &edge, &[10], 0.0, "red"), Ok(()));
+        // Turned, the line's end cap swings off the board
+        assert!(validate_piece(&ShapeType::Line, &edge, &[10], 45.0, "red").is_err());
+        let circle = [Position { x: 5, y: 100 }];
+        assert!(validate_piece(&ShapeType::Circle, &circle, &[20], 0.0, "red").is_err());
+    }
 }
@@ -0,0 +1,302 @@
+//! Plain-text army lists, so a whole roster can be put on the table at once.
+//!
+//! Each non-empty line is either a header or a unit entry. Lines starting
+//! with `#` are comments.
+//!
+//! ```text
+//! # Headers apply to every entry below them
+//! Faction: Ultramarines
+//! Color: #1e40af
+//!
+//! # [count x] name | base | stats
+//! 10x Intercessor | circle 32 | wounds=2/2, save=3
+//! Captain | circle 40 | wounds=5/5, leader=true, title="Lord of the Fifth"
+//! 1x Land Raider | rect 100x60 | wounds=16/16
+//! ```
+//!
+//! Bases are `circle <diameter>`, `square <side>` or `rect <width>x<height>`.
+//! Stats are `name=value` or `name=value/max`, where the value is `true` or
+//! `false`, a whole or decimal number, or text, optionally in double quotes.
+//!
+//! A `Faction:` the game doesn't have yet is created on import, and a player
+//! who isn't on a side in the game yet joins it. Otherwise players can only
+//! import units for their own faction; the game master can import for any.
+
+use crate::{validate_attribute, validate_color, AttributeValue, TemplateAttribute};
+use tabletap_geometry::ShapeType;
+
+/// Colour used for entries that come before any `Color:` header.
+const DEFAULT_ROSTER_COLOR: &str = "gray";
+
+/// One line of a roster: `count` identical units.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RosterEntry {
+    pub name: String,
+    pub count: u32,
+    pub faction: Option<String>,
+    pub color: String,
+    pub shape_type: ShapeType,
+    pub size: Vec<u32>,
+    pub attributes: Vec<TemplateAttribute>,
+}
+
+/// Parses a roster, reporting the first bad line by number.
+pub fn parse_roster(roster: &str) -> Result<Vec<RosterEntry>, String> {
+    let mut faction = None;
+    let mut color = DEFAULT_ROSTER_COLOR.to_string();
+    let mut entries = Vec::new();
+    for (number, line) in roster.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let in_line = |error: String| format!("Line {}: {}", number + 1, error);
+        if let Some((header, value)) = split_header(line) {
+            let value = value.trim();
+            match header.as_str() {
+                "faction" => faction = (!value.is_empty()).then(|| value.to_string()),
+                "color" | "colour" => {
+                    validate_color(value).map_err(in_line)?;
+                    color = value.to_string();
+                }
+                _ => return Err(in_line(format!("unknown header {}", header))),
+            }
+            continue;
+        }
+        let (name, count, shape_type, size, attributes) = parse_entry(line).map_err(in_line)?;
+        entries.push(RosterEntry {
+            name,
+            count,
+            faction: faction.clone(),
+            color: color.clone(),
+            shape_type,
+            size,
+            attributes,
+        });
+    }
+    if entries.is_empty() {
+        return Err("The roster has no units".to_string());
+    }
+    Ok(entries)
+}
+
+/// Splits `Header: value` lines. Entries can't be mistaken for headers as the
+/// part before the colon would have to be a single word without a `|`.
+fn split_header(line: &str) -> Option<(String, &str)> {
+    let (header, value) = line.split_once(':')?;
+    let header = header.trim();
+    (!header.is_empty() && header.chars().all(|c| c.is_ascii_alphabetic())).then(|| (header.to_ascii_lowercase(), value))
+}
+
+type Entry = (String, u32, ShapeType, Vec<u32>, Vec<TemplateAttribute>);
+
+fn parse_entry(line: &str) -> Result<Entry, String> {
+    let mut fields = line.split('|').map(str::trim);
+    let (count, name) = parse_count(fields.next().unwrap_or_default())?;
+    let (shape_type, size) = parse_base(fields.next().ok_or(format!("{} has no base size", name))?)?;
+    let attributes = match fields.next() {
+        Some(stats) => parse_stats(stats)?,
+        None => Vec::new(),
+    };
+    if fields.next().is_some() {
+        return Err(format!("{} has more than three fields", name));
+    }
+    Ok((name, count, shape_type, size, attributes))
+}
+
+/// Reads an optional `10x` prefix off the unit name.
+fn parse_count(field: &str) -> Result<(u32, String), String> {
+    let (count, name) = match field.split_once(char::is_whitespace) {
+        Some((prefix, rest)) if prefix.len() > 1 && prefix.to_ascii_lowercase().ends_with('x') => {
+            match prefix[..prefix.len() - 1].parse::<u32>() {
+                Ok(count) => (count, rest.trim()),
+                Err(_) => (1, field),
+            }
+        }
+        _ => (1, field),
+    };
+    if name.is_empty() {
+        return Err("Units need a name".to_string());
+    }
+    if count == 0 {
+        return Err(format!("{} has a count of 0", name));
+    }
+    Ok((count, name.to_string()))
+}
+
+fn parse_base(field: &str) -> Result<(ShapeType, Vec<u32>), String> {
+    let invalid = || format!("{} is not a base size; use circle 32, square 25 or rect 60x35", field);
+    let (kind, dimensions) = field.split_once(char::is_whitespace).ok_or_else(invalid)?;
+    let number = |text: &str| text.trim().parse::<u32>().ok().filter(|&value| value > 0);
+    match kind.to_ascii_lowercase().as_str() {
+        "circle" => Ok((ShapeType::Circle, vec![number(dimensions).ok_or_else(invalid)?])),
+        "square" => {
+            let side = number(dimensions).ok_or_else(invalid)?;
+            Ok((ShapeType::Rectangle, vec![side, side]))
+        }
+        "rect" | "rectangle" => {
+            let (width, height) = dimensions.split_once(['x', 'X']).ok_or_else(invalid)?;
+            Ok((ShapeType::Rectangle, vec![number(width).ok_or_else(invalid)?, number(height).ok_or_else(invalid)?]))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_stats(field: &str) -> Result<Vec<TemplateAttribute>, String> {
+    let mut attributes: Vec<TemplateAttribute> = Vec::new();
+    for stat in split_stats(field) {
+        let (name, value) = stat.split_once('=').ok_or(format!("{} is not name=value", stat))?;
+        let (value, max) = match value.trim().rsplit_once('/') {
+            Some((value, max)) if !value.trim_start().starts_with('"') => {
+                let max = max.trim().parse::<f64>().map_err(|_| format!("{} is not a number", max.trim()))?;
+                (parse_value(value), Some(max))
+            }
+            _ => (parse_value(value), None),
+        };
+        let name = validate_attribute(name, &value, max)?;
+        if attributes.iter().any(|other| other.name == name) {
+            return Err(format!("Attribute {} is listed twice", name));
+        }
+        attributes.push(TemplateAttribute { name, value, max });
+    }
+    Ok(attributes)
+}
+
+/// Splits on commas outside double quotes.
+fn split_stats(field: &str) -> Vec<&str> {
+    let mut stats = Vec::new();
+    let mut start = 0;
+    let mut quoted = false;
+    for (index, c) in field.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            ',' if !quoted => {
+                stats.push(&field[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    stats.push(&field[start..]);
+    stats.into_iter().map(str::trim).filter(|stat| !stat.is_empty()).collect()
+}
+
+fn parse_value(text: &str) -> AttributeValue {
+    let text = text.trim();
+    if let Some(quoted) = text.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+        return AttributeValue::Str(quoted.to_string());
+    }
+    match text.to_ascii_lowercase().as_str() {
+        "true" => return AttributeValue::Bool(true),
+        "false" => return AttributeValue::Bool(false),
+        _ => {}
+    }
+    if let Ok(number) = text.parse::<i64>() {
+        AttributeValue::Int(number)
+    } else if let Ok(number) = text.parse::<f64>() {
+        AttributeValue::Float(number)
+    } else {
+        AttributeValue::Str(text.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attribute(name: &str, value: AttributeValue, max: Option<f64>) -> TemplateAttribute {
+        TemplateAttribute { name: name.to_string(), value, max }
+    }
+
+    #[test]
+    fn parses_the_documented_example() {
+        let roster = "\
+# Headers apply to every entry below them
+Faction: Ultramarines
+Color: #1e40af
+
+# [count x] name | base | stats
+10x Intercessor | circle 32 | wounds=2/2, save=3
+Captain | circle 40 | wounds=5/5, leader=true, title=\"Lord of the Fifth\"
+1x Land Raider | rect 100x60 | wounds=16/16
+";
+        let entries = parse_roster(roster).unwrap();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries[0],
+            RosterEntry {
+                name: "Intercessor".to_string(),
+                count: 10,
+                faction: Some("Ultramarines".to_string()),
+                color: "#1e40af".to_string(),
+                shape_type: ShapeType::Circle,
+                size: vec![32],
+                attributes: vec![
+                    attribute("wounds", AttributeValue::Int(2), Some(2.0)),
+                    attribute("save", AttributeValue::Int(3), None),
+                ],
+            }
+        );
+        assert_eq!(entries[1].count, 1);
+        assert_eq!(
+            entries[1].attributes,
+            vec![
+                attribute("wounds", AttributeValue::Int(5), Some(5.0)),
+                attribute("leader", AttributeValue::Bool(true), None),
+                attribute("title", AttributeValue::Str("Lord of the Fifth".to_string()), None),
+            ]
+        );
+        assert_eq!((entries[2].name.as_str(), entries[2].count), ("Land Raider", 1));
+        assert_eq!((entries[2].shape_type, entries[2].size.clone()), (ShapeType::Rectangle, vec![100, 60]));
+    }
+
+    #[test]
+    fn headers_apply_until_changed() {
+        let roster = "Guard | square 25\nFaction: Red\nColour: red\nScout | circle 25\nFaction:\nDrone | circle 20";
+        let entries = parse_roster(roster).unwrap();
+        assert_eq!((entries[0].faction.clone(), entries[0].color.as_str()), (None, DEFAULT_ROSTER_COLOR));
+        assert_eq!(entries[0].size, vec![25, 25]);
+        assert_eq!((entries[1].faction.clone(), entries[1].color.as_str()), (Some("Red".to_string()), "red"));
+        assert_eq!((entries[2].faction.clone(), entries[2].color.as_str()), (None, "red"));
+    }
+
+    #[test]
+    fn reads_counts_and_values() {
+        let entries = parse_roster("3X Xeno Warrior | circle 30 | speed=5.5, note=slow, tag=\"a, b\", armor=2/3.5").unwrap();
+        assert_eq!((entries[0].name.as_str(), entries[0].count), ("Xeno Warrior", 3));
+        assert_eq!(
+            entries[0].attributes,
+            vec![
+                attribute("speed", AttributeValue::Float(5.5), None),
+                attribute("note", AttributeValue::Str("slow".to_string()), None),
+                attribute("tag", AttributeValue::Str("a, b".to_string()), None),
+                attribute("armor", AttributeValue::Int(2), Some(3.5)),
+            ]
+        );
+        // A name starting with a word ending in x is not a count
+        assert_eq!(parse_roster("Max Power | circle 30").unwrap()[0].name, "Max Power");
+    }
+
+    #[test]
+    fn reports_the_bad_line() {
+        let cases = [
+            ("", "The roster has no units"),
+            ("# just a comment", "The roster has no units"),
+            ("Guard | circle 30\nTeam: Red", "Line 2: unknown header team"),
+            ("Color: #12345", "Line 1:"),
+            ("0x Guard | circle 30", "Line 1: Guard has a count of 0"),
+            ("Guard", "Line 1: Guard has no base size"),
+            ("Guard | hexagon 30", "Line 1: hexagon 30 is not a base size"),
+            ("Guard | circle 0", "Line 1: circle 0 is not a base size"),
+            ("Guard | rect 30", "Line 1: rect 30 is not a base size"),
+            ("Guard | circle 30 | hp=3 | extra", "Line 1: Guard has more than three fields"),
+            ("Guard | circle 30 | hp", "Line 1: hp is not name=value"),
+            ("Guard | circle 30 | hp=3/lots", "Line 1: lots is not a number"),
+            ("Guard | circle 30 | hp=3, hp=4", "Line 1: Attribute hp is listed twice"),
+        ];
+        for (roster, expected) in cases {
+            let error = parse_roster(roster).unwrap_err();
+            assert!(error.starts_with(expected), "{:?}: {}", roster, error);
+        }
+    }
+}